@@ -0,0 +1,164 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+
+use super::parse;
+use super::{Answer, Error, Reader, Result};
+
+/// Where the known answers live, one `day part input answer` per line.
+pub const ANSWERS_FILE: &str = "./input/answers.txt";
+
+/// An answer that is known to be right for one part of a day on one input file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnownAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+}
+
+/// How an answer compares to the known ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Verified,
+    Wrong { expected: String },
+    Unknown,
+}
+
+/// "./input/day7.txt" and "input/day7.txt" are the same file.
+pub fn same_input(a: &str, b: &str) -> bool {
+    Path::new(a.trim_start_matches("./")) == Path::new(b.trim_start_matches("./"))
+}
+
+impl KnownAnswer {
+    pub fn is_for(&self, day: u8, part: u8, input: &str) -> bool {
+        self.day == day && self.part == part && same_input(&self.input, input)
+    }
+}
+
+/// Parses the answers, empty lines and lines starting with `#` are skipped.
+pub fn parse_answers(data: &str) -> Result<Vec<KnownAnswer>> {
+    parse::lines(data)
+        .filter(|line| !line.text.trim().is_empty() && !line.text.starts_with('#'))
+        .map(|line| {
+            let mut words = line.words();
+            let mut next = |what: &str| {
+                words
+                    .next()
+                    .ok_or_else(|| line.error(format!("missing {what}")))
+            };
+            let day = next("day")?.parse()?;
+            let part = next("part")?.parse()?;
+            let input = next("input")?.text.to_string();
+            let answer = next("answer")?.text.to_string();
+            if let Some(extra) = words.next() {
+                return Err(extra.error("unexpected text after the answer"));
+            }
+            Ok(KnownAnswer {
+                day,
+                part,
+                input,
+                answer,
+            })
+        })
+        .collect()
+}
+
+/// Reads the answers file, a missing file simply means no answers are known yet.
+pub fn load_answers(path: &str) -> Result<Vec<KnownAnswer>> {
+    match Reader::read_to_string(path) {
+        Ok(data) => parse_answers(&data).map_err(|e| e.in_file(path)),
+        Err(Error::Io { source, .. }) if source.kind() == std::io::ErrorKind::NotFound => {
+            Ok(Vec::new())
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Adds a line for `known` to the answers file.
+pub fn record_answer(path: &str, known: &KnownAnswer) -> Result<()> {
+    let starts_new_line =
+        Reader::read_to_string(path).map_or(true, |d| d.is_empty() || d.ends_with('\n'));
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| Error::io(path, e))?;
+    writeln!(
+        file,
+        "{}{} {} {} {}",
+        if starts_new_line { "" } else { "\n" },
+        known.day,
+        known.part,
+        known.input,
+        known.answer
+    )
+    .map_err(|e| Error::io(path, e))
+}
+
+/// Compares `answer` with the known answer for this day, part and input, if there is one.
+pub fn verdict(
+    answers: &[KnownAnswer],
+    day: u8,
+    part: u8,
+    input: &str,
+    answer: &Answer,
+) -> Verdict {
+    match answers.iter().find(|known| known.is_for(day, part, input)) {
+        Some(known) if known.answer == answer.to_string() => Verdict::Verified,
+        Some(known) => Verdict::Wrong {
+            expected: known.answer.clone(),
+        },
+        None => Verdict::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load_answers, parse_answers, record_answer, verdict, KnownAnswer, Verdict};
+    use crate::advent_of_code::temp_dir::TempDir;
+    use crate::advent_of_code::{Answer, Error};
+
+    #[test]
+    fn parse_answers_test() {
+        let answers =
+            parse_answers("# comment\n\n7 1 input/day7.txt 3749\n17 1 x.txt 4,6,3\n").unwrap();
+        assert_eq!(answers.len(), 2);
+        assert_eq!(answers[1].answer, "4,6,3");
+        assert!(answers[0].is_for(7, 1, "./input/day7.txt"));
+        assert!(!answers[0].is_for(7, 2, "input/day7.txt"));
+        let e = parse_answers("7 1 input/day7.txt\n").unwrap_err();
+        assert!(matches!(e, Error::Parse { line: 1, .. }));
+        assert!(parse_answers("7 x input/day7.txt 1\n").is_err());
+    }
+
+    #[test]
+    fn record_answer_test() {
+        let dir = TempDir::new("answers_test");
+        let path = dir.join("answers.txt");
+        let path = path.to_str().unwrap();
+        std::fs::write(path, "7 1 input/day7.txt 3749").unwrap();
+        let known = KnownAnswer {
+            day: 7,
+            part: 2,
+            input: "input/day7.txt".into(),
+            answer: "11387".into(),
+        };
+        record_answer(path, &known).unwrap();
+        assert_eq!(load_answers(path).unwrap()[1], known);
+    }
+
+    #[test]
+    fn verdict_test() {
+        let answers = parse_answers("7 1 input/day7.txt 3749").unwrap();
+        let check = |part, answer: Answer| verdict(&answers, 7, part, "input/day7.txt", &answer);
+        assert_eq!(check(1, 3749.into()), Verdict::Verified);
+        assert_eq!(
+            check(1, 3748.into()),
+            Verdict::Wrong {
+                expected: "3749".into()
+            }
+        );
+        assert_eq!(check(2, 3749.into()), Verdict::Unknown);
+    }
+}
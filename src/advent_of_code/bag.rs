@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A multiset counting how often every item is in it, for simulations where only the
+/// number of equal items matters and not their order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bag<T: Eq + Hash> {
+    counts: HashMap<T, u64>,
+}
+
+impl<T: Eq + Hash> Default for Bag<T> {
+    fn default() -> Self {
+        Self {
+            counts: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Eq + Hash> Bag<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `n` more of `item`, returning the new count, or `None` if it would overflow.
+    /// The bag is left as it was then.
+    pub fn checked_add(&mut self, item: T, n: u64) -> Option<u64> {
+        let count = self
+            .counts
+            .get(&item)
+            .copied()
+            .unwrap_or(0)
+            .checked_add(n)?;
+        if count > 0 {
+            self.counts.insert(item, count);
+        }
+        Some(count)
+    }
+
+    /// Adds `n` more of `item`, panics if the count overflows, in release builds too.
+    pub fn add(&mut self, item: T, n: u64) -> u64 {
+        self.checked_add(item, n)
+            .expect("The count does not fit into a u64.")
+    }
+
+    pub fn insert(&mut self, item: T) -> u64 {
+        self.add(item, 1)
+    }
+
+    pub fn count(&self, item: &T) -> u64 {
+        self.counts.get(item).copied().unwrap_or(0)
+    }
+
+    /// The number of different items.
+    pub fn distinct(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// The number of all items, `None` if it does not fit into a u64.
+    pub fn checked_total(&self) -> Option<u64> {
+        self.counts
+            .values()
+            .try_fold(0u64, |sum, &n| sum.checked_add(n))
+    }
+
+    /// The number of all items, panics if it does not fit into a u64.
+    pub fn total(&self) -> u64 {
+        self.checked_total()
+            .expect("The total does not fit into a u64.")
+    }
+
+    /// Every item with its count, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&T, u64)> {
+        self.counts.iter().map(|(item, &n)| (item, n))
+    }
+
+    /// Replaces every item by the ones `f` makes from it, each of them as often as the
+    /// item was there. `None` if a count overflows.
+    pub fn checked_flat_map<U, I>(&self, mut f: impl FnMut(&T) -> I) -> Option<Bag<U>>
+    where
+        U: Eq + Hash,
+        I: IntoIterator<Item = U>,
+    {
+        let mut next = Bag::new();
+        for (item, n) in self.iter() {
+            for new in f(item) {
+                next.checked_add(new, n)?;
+            }
+        }
+        Some(next)
+    }
+
+    /// Like `checked_flat_map`, but panics if a count overflows.
+    pub fn flat_map<U, I>(&self, f: impl FnMut(&T) -> I) -> Bag<U>
+    where
+        U: Eq + Hash,
+        I: IntoIterator<Item = U>,
+    {
+        self.checked_flat_map(f)
+            .expect("A count does not fit into a u64.")
+    }
+}
+
+impl<T: Eq + Hash> FromIterator<T> for Bag<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(items: I) -> Self {
+        let mut bag = Bag::new();
+        for item in items {
+            bag.insert(item);
+        }
+        bag
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Bag;
+
+    #[test]
+    fn bag_test() {
+        let mut bag: Bag<char> = "abracadabra".chars().collect();
+        assert_eq!(bag.count(&'a'), 5);
+        assert_eq!(bag.count(&'z'), 0);
+        assert_eq!((bag.distinct(), bag.total()), (5, 11));
+        assert_eq!(bag.add('z', 0), 0);
+        assert_eq!(bag.distinct(), 5);
+
+        assert_eq!(bag.checked_add('a', u64::MAX), None);
+        assert_eq!(bag.count(&'a'), 5);
+        bag.add('z', u64::MAX);
+        assert_eq!(bag.checked_total(), None);
+    }
+
+    #[test]
+    fn flat_map_test() {
+        // Every cell splits in two each generation, a lot of cells but a single value.
+        let mut cells: Bag<u8> = [1].into_iter().collect();
+        for _ in 0..63 {
+            cells = cells.flat_map(|&c| [c, c]);
+        }
+        assert_eq!(cells.total(), 1 << 63);
+        assert_eq!(cells.checked_flat_map(|&c| [c, c]), None);
+
+        let parity = Bag::from_iter(1..=10).flat_map(|n| [n % 2]);
+        assert_eq!((parity.count(&0), parity.count(&1)), (5, 5));
+    }
+}
@@ -0,0 +1,241 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::parse;
+use super::Result;
+
+/// The timings of one run of a day, a part that is not solved yet has no time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhaseTimes {
+    pub parse: Duration,
+    pub parts: [Option<Duration>; 2],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "part1"),
+            Phase::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+impl FromStr for Phase {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Phase::Parse),
+            "part1" => Ok(Phase::Part1),
+            "part2" => Ok(Phase::Part2),
+            _ => Err(format!("unknown phase '{s}'")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort();
+        Some(Self {
+            min: *samples.first()?,
+            median: samples[samples.len() / 2],
+            max: *samples.last()?,
+        })
+    }
+}
+
+/// The timing of one phase of one day over a number of iterations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub phase: Phase,
+    pub iterations: usize,
+    pub stats: Stats,
+}
+
+/// Runs `time` on the input `iterations` times and summarizes every phase.
+pub fn measure(
+    day: u8,
+    time: fn(&str) -> Result<PhaseTimes>,
+    data: &str,
+    iterations: usize,
+) -> Result<Vec<Record>> {
+    let runs: Vec<PhaseTimes> = (0..iterations).map(|_| time(data)).collect::<Result<_>>()?;
+    let phases: [(Phase, Option<Vec<Duration>>); 3] = [
+        (Phase::Parse, runs.iter().map(|r| Some(r.parse)).collect()),
+        (Phase::Part1, runs.iter().map(|r| r.parts[0]).collect()),
+        (Phase::Part2, runs.iter().map(|r| r.parts[1]).collect()),
+    ];
+    Ok(phases
+        .into_iter()
+        .filter_map(|(phase, samples)| {
+            Some(Record {
+                day,
+                phase,
+                iterations,
+                stats: Stats::from_samples(samples?)?,
+            })
+        })
+        .collect())
+}
+
+/// A tab separated report with one record per line, durations in nanoseconds.
+pub fn write_report(records: &[Record]) -> String {
+    let mut report = String::from("# day\tphase\titerations\tmin_ns\tmedian_ns\tmax_ns\n");
+    for r in records {
+        report += &format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\n",
+            r.day,
+            r.phase,
+            r.iterations,
+            r.stats.min.as_nanos(),
+            r.stats.median.as_nanos(),
+            r.stats.max.as_nanos()
+        );
+    }
+    report
+}
+
+pub fn parse_report(data: &str) -> Result<Vec<Record>> {
+    parse::lines(data)
+        .filter(|line| !line.text.trim().is_empty() && !line.text.starts_with('#'))
+        .map(|line| {
+            let fields: Vec<_> = line.split("\t").collect();
+            let [day, phase, iterations, min, median, max] = fields[..] else {
+                return Err(line.error("expected 6 tab separated fields"));
+            };
+            let nanos = |field: parse::Span| field.parse().map(Duration::from_nanos);
+            Ok(Record {
+                day: day.parse()?,
+                phase: phase.parse()?,
+                iterations: iterations.parse()?,
+                stats: Stats {
+                    min: nanos(min)?,
+                    median: nanos(median)?,
+                    max: nanos(max)?,
+                },
+            })
+        })
+        .collect()
+}
+
+/// A phase whose median got slower than allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Regression {
+    pub day: u8,
+    pub phase: Phase,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {} {}: {:.2?} -> {:.2?} ({:+.0}%)",
+            self.day,
+            self.phase,
+            self.before,
+            self.after,
+            (self.after.as_secs_f64() / self.before.as_secs_f64() - 1.0) * 100.0
+        )
+    }
+}
+
+/// Compares the medians of the phases in both reports, `tolerance` is a fraction like 0.2 for 20%.
+pub fn regressions(before: &[Record], after: &[Record], tolerance: f64) -> Vec<Regression> {
+    after
+        .iter()
+        .filter_map(|new| {
+            let old = before
+                .iter()
+                .find(|old| old.day == new.day && old.phase == new.phase)?;
+            let limit = old.stats.median.mul_f64(1.0 + tolerance);
+            (new.stats.median > limit).then_some(Regression {
+                day: new.day,
+                phase: new.phase,
+                before: old.stats.median,
+                after: new.stats.median,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{measure, parse_report, regressions, write_report, Phase, PhaseTimes, Stats};
+    use crate::advent_of_code::Error;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn stats_test() {
+        let stats = Stats::from_samples(vec![ms(5), ms(1), ms(3), ms(9), ms(2)]).unwrap();
+        assert_eq!((stats.min, stats.median, stats.max), (ms(1), ms(3), ms(9)));
+        assert_eq!(Stats::from_samples(vec![]), None);
+    }
+
+    #[test]
+    fn measure_test() {
+        let time = |_: &str| {
+            Ok(PhaseTimes {
+                parse: ms(1),
+                parts: [Some(ms(2)), None],
+            })
+        };
+        let records = measure(7, time, "", 3).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].phase, Phase::Part1);
+        assert_eq!(records[1].stats.median, ms(2));
+        assert_eq!(records[1].iterations, 3);
+
+        let broken = |_: &str| Err(Error::MissingSection("program".to_string()));
+        assert!(measure(7, broken, "", 3).is_err());
+    }
+
+    #[test]
+    fn report_test() {
+        let time = |_: &str| {
+            Ok(PhaseTimes {
+                parse: ms(1),
+                parts: [Some(ms(2)), Some(ms(3))],
+            })
+        };
+        let before = measure(7, time, "", 2).unwrap();
+        assert_eq!(parse_report(&write_report(&before)).unwrap(), before);
+        assert!(parse_report("7\tparse\t1\t2\t3").is_err());
+
+        let slower = |_: &str| {
+            Ok(PhaseTimes {
+                parse: ms(1),
+                parts: [Some(ms(2)), Some(ms(4))],
+            })
+        };
+        let after = measure(7, slower, "", 2).unwrap();
+        let found = regressions(&before, &after, 0.2);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].phase, Phase::Part2);
+        assert_eq!(found[0].to_string(), "day 7 part2: 3.00ms -> 4.00ms (+33%)");
+        assert!(regressions(&before, &after, 0.5).is_empty());
+    }
+}
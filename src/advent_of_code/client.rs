@@ -0,0 +1,325 @@
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use super::{Error, Reader, Result};
+
+// Talking to adventofcode.com. There is no HTTP crate in the build, so requests go
+// through `curl`. The session cookie is handed over on stdin and never shows up in
+// the process list.
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2024;
+
+/// Tells the site who is calling, as asked for by its maintainers.
+pub const USER_AGENT: &str = "github.com/kivel/AoC_2024 aoc runner via curl";
+
+/// The environment variable with the session cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// The file in the repository root with the session cookie, it is ignored by git.
+pub const SESSION_FILE: &str = ".aoc_session";
+/// Points the client to another server, like a local stand-in.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+// The first non-empty token wins: environment first, then the files in order.
+fn find_session(from_env: Option<String>, files: &[PathBuf]) -> Result<String> {
+    from_env
+        .into_iter()
+        .chain(
+            files
+                .iter()
+                .filter_map(|file| Reader::read_to_string(file).ok()),
+        )
+        .map(|token| token.trim().to_string())
+        .find(|token| !token.is_empty())
+        .ok_or_else(|| {
+            Error::Config(format!(
+                "no session token, set {SESSION_VAR} or put it into {SESSION_FILE}"
+            ))
+        })
+}
+
+/// Reads the session token from `AOC_SESSION`, `.aoc_session` or `~/.config/aoc/session`.
+pub fn session_token(root: &Path) -> Result<String> {
+    let mut files = vec![root.join(SESSION_FILE)];
+    if let Some(home) = env::var_os("HOME") {
+        files.push(PathBuf::from(home).join(".config/aoc/session"));
+    }
+    find_session(env::var(SESSION_VAR).ok(), &files)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+        }
+    }
+
+    /// A client for the real site, or for `AOC_BASE_URL` if it is set.
+    pub fn from_env(root: &Path) -> Result<Self> {
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| BASE_URL.to_string());
+        Ok(Self::new(base_url, session_token(root)?))
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    pub fn get(&self, path: &str) -> Result<Response> {
+        self.request(path, None)
+    }
+
+    /// Posts `form`, which has to be url-encoded already.
+    pub fn post(&self, path: &str, form: &str) -> Result<Response> {
+        self.request(path, Some(form))
+    }
+
+    fn request(&self, path: &str, form: Option<&str>) -> Result<Response> {
+        let url = self.url(path);
+        let failed = |message: String| Error::Http {
+            url: url.clone(),
+            message,
+        };
+
+        let mut curl = Command::new("curl");
+        curl.args(["--silent", "--show-error", "--max-time", "30"])
+            .args(["--user-agent", USER_AGENT])
+            .args(["--header", "@-"])
+            .args(["--write-out", "\n%{http_code}"]);
+        if let Some(form) = form {
+            curl.args(["--data", form]);
+        }
+        let mut child = curl
+            .arg(&url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| failed(format!("could not run curl: {e}")))?;
+        if let Some(mut stdin) = child.stdin.take() {
+            writeln!(stdin, "Cookie: session={}", self.session)
+                .map_err(|e| failed(format!("could not pass the session to curl: {e}")))?;
+        }
+        let output = child
+            .wait_with_output()
+            .map_err(|e| failed(format!("curl did not finish: {e}")))?;
+        if !output.status.success() {
+            return Err(failed(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+
+        // `--write-out` puts the status code on a line of its own after the body.
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = stdout
+            .rsplit_once('\n')
+            .ok_or_else(|| failed("no status code in the response".into()))?;
+        let status = status
+            .trim()
+            .parse()
+            .map_err(|_| failed(format!("invalid status code {status:?}")))?;
+        Ok(Response {
+            status,
+            body: body.to_string(),
+        })
+    }
+
+    /// Downloads the personal puzzle input of `day`.
+    pub fn input(&self, day: u8) -> Result<String> {
+        let path = format!("/{YEAR}/day/{day}/input");
+        let response = self.get(&path)?;
+        let message = match response.status {
+            200 => return Ok(response.body),
+            404 => format!("day {day} is not unlocked yet"),
+            400 | 500 => "the session token was rejected, log in again and update it".to_string(),
+            status => format!("unexpected status {status}"),
+        };
+        Err(Error::Http {
+            url: self.url(&path),
+            message,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+/// Downloads the input of `day` into `path`, unless there is one already.
+///
+/// An empty file, like the ones from `aoc new`, does not count as downloaded.
+pub fn fetch_input(client: &Client, day: u8, path: &Path) -> Result<Fetched> {
+    if fs::metadata(path).is_ok_and(|meta| meta.len() > 0) {
+        return Ok(Fetched::Cached);
+    }
+    let input = client.input(day)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| Error::io(dir, e))?;
+    }
+    fs::write(path, input).map_err(|e| Error::io(path, e))?;
+    Ok(Fetched::Downloaded)
+}
+
+/// A small HTTP server on localhost standing in for the site in tests.
+#[cfg(test)]
+pub(crate) mod stand_in {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::process::Command;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    /// Requests go through `curl`, tests that need it are skipped on machines without it.
+    pub fn curl_missing() -> bool {
+        let missing = !Command::new("curl")
+            .arg("--version")
+            .output()
+            .is_ok_and(|output| output.status.success());
+        if missing {
+            eprintln!("curl is not installed, skipping the test");
+        }
+        missing
+    }
+
+    pub struct StandIn {
+        pub url: String,
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl StandIn {
+        /// Answers every request with `status` and `body` and records the requests.
+        pub fn start(status: u16, body: &'static str) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let recorded = Arc::clone(&requests);
+            thread::spawn(move || {
+                for mut stream in listener.incoming().flatten() {
+                    recorded.lock().unwrap().push(read_request(&stream));
+                    let _ = write!(
+                        stream,
+                        "HTTP/1.1 {status} Stand-In\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                }
+            });
+            Self { url, requests }
+        }
+
+        pub fn requests(&self) -> Vec<String> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    // The request line, the headers and the body, if there is one.
+    fn read_request(stream: &TcpStream) -> String {
+        let mut reader = BufReader::new(stream);
+        let mut request = String::new();
+        let mut length = 0;
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                break;
+            }
+            if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                length = value.trim().parse().unwrap_or(0);
+            }
+            request += &line;
+        }
+        let mut body = vec![0; length];
+        let _ = reader.read_exact(&mut body);
+        request + "\r\n" + &String::from_utf8_lossy(&body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::stand_in::{curl_missing, StandIn};
+    use super::{fetch_input, find_session, Client, Fetched, USER_AGENT};
+    use crate::advent_of_code::temp_dir::TempDir;
+    use crate::advent_of_code::Error;
+
+    #[test]
+    fn find_session_test() {
+        let dir = TempDir::new("session_test");
+        let file = dir.join("session");
+        fs::write(&file, "from-file\n").unwrap();
+        let files = [file.with_extension("missing"), file.clone()];
+        assert_eq!(find_session(Some("env".into()), &files).unwrap(), "env");
+        assert_eq!(find_session(Some(" ".into()), &files).unwrap(), "from-file");
+        assert!(matches!(
+            find_session(None, &files[..1]),
+            Err(Error::Config(_))
+        ));
+    }
+
+    #[test]
+    fn fetch_input_test() {
+        if curl_missing() {
+            return;
+        }
+        let server = StandIn::start(200, "1 2 3\n4 5 6\n");
+        let client = Client::new(&server.url, "secret");
+        let dir = TempDir::new("fetch_test");
+        let path = dir.join("input/day7.txt");
+
+        assert_eq!(fetch_input(&client, 7, &path).unwrap(), Fetched::Downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n4 5 6\n");
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2024/day/7/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=secret\r\n"));
+        assert!(requests[0].contains(&format!("User-Agent: {USER_AGENT}\r\n")));
+
+        // Cached now, the server is not asked again.
+        assert_eq!(fetch_input(&client, 7, &path).unwrap(), Fetched::Cached);
+        assert_eq!(server.requests().len(), 1);
+
+        // An empty placeholder is replaced.
+        fs::write(&path, "").unwrap();
+        assert_eq!(fetch_input(&client, 7, &path).unwrap(), Fetched::Downloaded);
+    }
+
+    #[test]
+    fn fetch_input_error_test() {
+        if curl_missing() {
+            return;
+        }
+        let server = StandIn::start(404, "Please don't repeatedly request this endpoint.");
+        let client = Client::new(&server.url, "secret");
+        let dir = TempDir::new("fetch_error_test");
+        let path = dir.join("day25.txt");
+        let e = fetch_input(&client, 25, &path).unwrap_err();
+        assert!(e.to_string().ends_with("day 25 is not unlocked yet"));
+        assert!(!path.exists());
+
+        let server = StandIn::start(400, "Puzzle inputs differ by user.  Please log in.");
+        let e = Client::new(&server.url, "expired").input(1).unwrap_err();
+        assert!(e.to_string().contains("session token was rejected"));
+
+        // Nobody listening at all.
+        let e = Client::new("http://127.0.0.1:9", "secret")
+            .input(1)
+            .unwrap_err();
+        assert!(matches!(e, Error::Http { .. }));
+    }
+}
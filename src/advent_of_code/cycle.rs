@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Finding the loop in a deterministic simulation: a state and a `step` function that
+// gives the next state. A simulation that halts can be modelled with a final state that
+// steps to itself, it shows up as a cycle of length 1.
+
+/// Step `start` is the first one whose state comes back, `length` steps later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The first step with the same state as step `n`, so `n` can be huge.
+    pub fn first_equal_step(&self, n: usize) -> usize {
+        match n < self.start {
+            true => n,
+            false => self.start + (n - self.start) % self.length,
+        }
+    }
+}
+
+/// All states of a simulation up to its first repeated state.
+#[derive(Debug, Clone)]
+pub struct Run<S> {
+    pub cycle: Cycle,
+    // The states of the steps before the cycle and of one pass through it.
+    states: Vec<S>,
+}
+
+impl<S> Run<S> {
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+
+    /// The state after `n` steps, step 0 is the initial state.
+    pub fn state_at(&self, n: usize) -> &S {
+        &self.states[self.cycle.first_equal_step(n)]
+    }
+}
+
+/// Steps until a state repeats, remembering every state in a hash map.
+pub fn find_cycle<S>(initial: S, mut step: impl FnMut(&S) -> S) -> Run<S>
+where
+    S: Clone + Eq + Hash,
+{
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = initial;
+    loop {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle {
+                start,
+                length: states.len() - start,
+            };
+            return Run { cycle, states };
+        }
+        seen.insert(state.clone(), states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+}
+
+/// Brent's algorithm: finds the same cycle as `find_cycle` while keeping only two
+/// states, for simulations with big states or long runs before the cycle.
+pub fn brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // Find the length: the hare runs ahead, the tortoise jumps to it at every power of two.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // Find the start: with the hare `length` steps ahead both meet at the start.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+/// The state after `n` steps using `brent`, stepping at most through the start and one cycle.
+pub fn brent_state_at<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let cycle = brent(initial.clone(), &mut step);
+    (0..cycle.first_equal_step(n)).fold(initial, |state, _| step(&state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{brent, brent_state_at, find_cycle, Cycle};
+    use crate::advent_of_code::Direction4;
+
+    // 3, 10, 101, 2, 5, 26, 167, 95, 101, ... repeats from step 2 every 6 steps.
+    fn step(x: &u64) -> u64 {
+        (x * x + 1) % 255
+    }
+
+    #[test]
+    fn find_cycle_test() {
+        let run = find_cycle(3, step);
+        assert_eq!(
+            run.cycle,
+            Cycle {
+                start: 2,
+                length: 6
+            }
+        );
+        assert_eq!(run.states(), [3, 10, 101, 2, 5, 26, 167, 95]);
+        assert_eq!(*run.state_at(2), 101);
+        assert_eq!(*run.state_at(8), 101);
+        assert_eq!(*run.state_at(1_000_000_000_000_000_000), 5);
+
+        let run = find_cycle(Direction4::N, |d| d.turn_right());
+        assert_eq!(
+            run.cycle,
+            Cycle {
+                start: 0,
+                length: 4
+            }
+        );
+        assert_eq!(*run.state_at(1_000_000_000_003), Direction4::W);
+    }
+
+    #[test]
+    fn brent_test() {
+        assert_eq!(brent(3, step), find_cycle(3, step).cycle);
+        assert_eq!(brent_state_at(3, step, 1_000_000_000_000_000_000), 5);
+        assert_eq!(brent_state_at(3, step, 1), 10);
+
+        // Counting down to 0 and staying there, the halted state is a cycle of length 1.
+        let halts = brent(5u32, |&n| n.saturating_sub(1));
+        assert_eq!(
+            halts,
+            Cycle {
+                start: 5,
+                length: 1
+            }
+        );
+    }
+}
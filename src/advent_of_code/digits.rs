@@ -0,0 +1,121 @@
+// Working with the decimal digits of unsigned numbers without going through strings.
+
+/// Decimal digit operations, everything that could overflow returns an `Option`.
+pub trait Digits: Copy + Sized {
+    /// The number of decimal digits, 0 has one.
+    fn digit_count(self) -> u32;
+
+    /// Splits after the first `k` digits like `str::split_at`: 253000 at 3 is (253, 0).
+    /// `None` if there are fewer than `k` digits.
+    fn split_digits_at(self, k: u32) -> Option<(Self, Self)>;
+
+    /// Writes the digits of `other` after the ones of `self`: 12 and 345 give 12345.
+    fn concat(self, other: Self) -> Option<Self>;
+
+    /// The digits, the most significant one first.
+    fn digits(self) -> impl DoubleEndedIterator<Item = u8>;
+
+    /// Whether the last digits are the ones of `suffix`: 12345 ends with 45.
+    fn ends_with(self, suffix: Self) -> bool;
+
+    /// What is left after removing the digits of `suffix` from the end, the inverse of `concat`.
+    fn strip_suffix(self, suffix: Self) -> Option<Self>;
+}
+
+macro_rules! digits {
+    ($($t:ty),*) => {
+        $(impl Digits for $t {
+            fn digit_count(self) -> u32 {
+                self.checked_ilog10().unwrap_or(0) + 1
+            }
+
+            fn split_digits_at(self, k: u32) -> Option<(Self, Self)> {
+                let right = self.digit_count().checked_sub(k)?;
+                // Overflows only if all digits go to the right.
+                match (10 as $t).checked_pow(right) {
+                    Some(divisor) => Some((self / divisor, self % divisor)),
+                    None => Some((0, self)),
+                }
+            }
+
+            fn concat(self, other: Self) -> Option<Self> {
+                (10 as $t)
+                    .checked_pow(other.digit_count())?
+                    .checked_mul(self)?
+                    .checked_add(other)
+            }
+
+            fn digits(self) -> impl DoubleEndedIterator<Item = u8> {
+                (0..self.digit_count())
+                    .rev()
+                    .map(move |i| (self / (10 as $t).pow(i) % 10) as u8)
+            }
+
+            fn ends_with(self, suffix: Self) -> bool {
+                match (10 as $t).checked_pow(suffix.digit_count()) {
+                    Some(divisor) => self % divisor == suffix,
+                    // `suffix` has the most digits there can be, so it is all of `self` or nothing.
+                    None => self == suffix,
+                }
+            }
+
+            fn strip_suffix(self, suffix: Self) -> Option<Self> {
+                if !self.ends_with(suffix) {
+                    return None;
+                }
+                Some(match (10 as $t).checked_pow(suffix.digit_count()) {
+                    Some(divisor) => self / divisor,
+                    None => 0,
+                })
+            }
+        })*
+    };
+}
+digits!(u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::Digits;
+
+    #[test]
+    fn digit_count_test() {
+        assert_eq!(0u64.digit_count(), 1);
+        assert_eq!(9u64.digit_count(), 1);
+        assert_eq!(10u64.digit_count(), 2);
+        assert_eq!(u64::MAX.digit_count(), 20);
+        assert_eq!(255u8.digit_count(), 3);
+    }
+
+    #[test]
+    fn split_test() {
+        assert_eq!(253000u64.split_digits_at(3), Some((253, 0)));
+        assert_eq!(1234u32.split_digits_at(0), Some((0, 1234)));
+        assert_eq!(1234u32.split_digits_at(4), Some((1234, 0)));
+        assert_eq!(1234u32.split_digits_at(5), None);
+        assert_eq!(255u8.split_digits_at(0), Some((0, 255)));
+        assert_eq!(u64::MAX.split_digits_at(10), Some((1844674407, 3709551615)));
+        assert_eq!(17u64.digits().collect::<Vec<_>>(), [1, 7]);
+        assert_eq!(0u8.digits().collect::<Vec<_>>(), [0]);
+        assert_eq!(120u8.digits().rev().collect::<Vec<_>>(), [0, 2, 1]);
+    }
+
+    #[test]
+    fn concat_test() {
+        assert_eq!(12u64.concat(345), Some(12345));
+        assert_eq!(15u64.concat(0), Some(150));
+        assert_eq!(25u8.concat(5), Some(255));
+        assert_eq!(25u8.concat(6), None);
+        assert_eq!(1u8.concat(100), None);
+        assert_eq!(u64::MAX.concat(1), None);
+
+        assert!(12345u64.ends_with(45));
+        assert!(!12345u64.ends_with(35));
+        assert!(5u64.ends_with(5));
+        assert!(!5u64.ends_with(15));
+        assert!(200u8.ends_with(200));
+        assert_eq!(12345u64.strip_suffix(45), Some(123));
+        assert_eq!(12u64.strip_suffix(12), Some(0));
+        assert_eq!(12u64.strip_suffix(3), None);
+        assert_eq!(200u8.strip_suffix(200), Some(0));
+    }
+}
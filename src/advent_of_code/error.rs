@@ -0,0 +1,166 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// Everything that can go wrong while reading and parsing puzzle input, writing new files
+/// or talking to the Advent of Code site.
+#[derive(Debug)]
+pub enum Error {
+    /// The file could not be opened or read.
+    Io { path: PathBuf, source: io::Error },
+    /// A line did not look like expected, `line` and `column` start at 1.
+    Parse {
+        file: Option<PathBuf>,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// A part of the input, like the rules or the program, is not there at all.
+    MissingSection(String),
+    /// A character that is not allowed at this place, `line` and `column` start at 1.
+    InvalidChar {
+        char: char,
+        line: usize,
+        column: usize,
+    },
+    /// Something that would be created is already there and is left alone.
+    AlreadyExists(String),
+    /// A request to the site failed or got an unexpected response.
+    Http { url: String, message: String },
+    /// Settings like the session token are missing or broken.
+    Config(String),
+    /// An answer is not submitted, because earlier attempts already rule it out.
+    Refused(String),
+    /// Something that needs at least one item, like a ring buffer, got none.
+    Empty(String),
+    /// Ordering rules that go round in a circle, so the nodes on `line` have no order.
+    Cycle { line: usize, nodes: Vec<String> },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn io(path: impl Into<PathBuf>, source: io::Error) -> Self {
+        Error::Io {
+            path: path.into(),
+            source,
+        }
+    }
+
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Error::Parse {
+            file: None,
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Attaches the file name to a parse error, other errors are returned as they are.
+    pub fn in_file(self, path: impl Into<PathBuf>) -> Self {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                message,
+                ..
+            } => Error::Parse {
+                file: Some(path.into()),
+                line,
+                column,
+                message,
+            },
+            other => other,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } if source.kind() == io::ErrorKind::NotFound => {
+                write!(f, "{} not found — did you download it?", path.display())
+            }
+            Error::Io { path, source } => {
+                write!(f, "could not access {}: {source}", path.display())
+            }
+            Error::Parse {
+                file: Some(file),
+                line,
+                column,
+                message,
+            } => write!(f, "{}:{line}:{column}: {message}", file.display()),
+            Error::Parse {
+                file: None,
+                line,
+                column,
+                message,
+            } => write!(f, "line {line}, column {column}: {message}"),
+            Error::MissingSection(section) => write!(f, "missing section: {section}"),
+            Error::InvalidChar { char, line, column } => {
+                write!(
+                    f,
+                    "invalid character {char:?} at line {line}, column {column}"
+                )
+            }
+            Error::AlreadyExists(what) => write!(f, "{what} already exists, not overwriting it"),
+            Error::Http { url, message } => write!(f, "request to {url} failed: {message}"),
+            Error::Config(message) => write!(f, "{message}"),
+            Error::Refused(reason) => write!(f, "not submitting, {reason}"),
+            Error::Empty(what) => write!(f, "{what} is empty"),
+            Error::Cycle { line, nodes } => {
+                write!(
+                    f,
+                    "line {line}: {} are ordered in a cycle",
+                    nodes.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::Error;
+    use crate::advent_of_code::Reader;
+
+    #[test]
+    fn display_test() {
+        let e = Error::io("input/day7.txt", io::ErrorKind::NotFound.into());
+        assert_eq!(
+            e.to_string(),
+            "input/day7.txt not found — did you download it?"
+        );
+        let e = Error::parse(3, 7, "expected a number").in_file("input/day7.txt");
+        assert_eq!(e.to_string(), "input/day7.txt:3:7: expected a number");
+        let e = Error::InvalidChar {
+            char: '?',
+            line: 2,
+            column: 1,
+        };
+        assert_eq!(e.to_string(), "invalid character '?' at line 2, column 1");
+        let e = Error::Cycle {
+            line: 4,
+            nodes: vec!["2".to_string(), "1".to_string()],
+        };
+        assert_eq!(e.to_string(), "line 4: 2, 1 are ordered in a cycle");
+    }
+
+    #[test]
+    fn reader_missing_file_test() {
+        let e = Reader::read_file("./input/day0.txt").unwrap_err();
+        assert!(matches!(e, Error::Io { source, .. } if source.kind() == io::ErrorKind::NotFound));
+        assert_eq!(Reader::read_file("./input/day11.txt").unwrap().len(), 1);
+    }
+}
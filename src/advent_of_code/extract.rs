@@ -0,0 +1,116 @@
+use std::fs;
+use std::path::Path;
+
+use super::{Error, Result};
+
+// Reading examples out of a puzzle page saved from the browser. The pages are
+// plain enough to get along with string search instead of an HTML parser.
+
+// The text between all pairs of `open` and `close`, with the position of each.
+fn between<'a>(html: &'a str, open: &str, close: &str) -> Vec<(usize, &'a str)> {
+    let mut found = Vec::new();
+    let mut rest = 0;
+    while let Some(start) = html[rest..].find(open).map(|i| rest + i + open.len()) {
+        let Some(end) = html[start..].find(close).map(|i| start + i) else {
+            break;
+        };
+        found.push((start, &html[start..end]));
+        rest = end + close.len();
+    }
+    found
+}
+
+fn strip_tags(s: &str) -> String {
+    let mut text = String::with_capacity(s.len());
+    let mut in_tag = false;
+    for c in s.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The text of all `<pre><code>` blocks, in the order of the page.
+pub fn code_blocks(html: &str) -> Vec<String> {
+    between(html, "<pre><code>", "</code></pre>")
+        .into_iter()
+        .map(|(_, block)| unescape(&strip_tags(block)))
+        .collect()
+}
+
+/// The emphasized values like `<code><em>143</em></code>`, which is how answers are shown.
+pub fn emphasized(html: &str) -> Vec<String> {
+    let mut found = between(html, "<code><em>", "</em></code>");
+    found.extend(between(html, "<em><code>", "</code></em>"));
+    found.sort();
+    found
+        .into_iter()
+        .map(|(_, value)| unescape(&strip_tags(value)))
+        .collect()
+}
+
+/// Writes an example input, an empty placeholder from `aoc new` may be replaced.
+pub fn write_example(path: &Path, content: &str) -> Result<()> {
+    if fs::metadata(path).is_ok_and(|meta| meta.len() > 0) {
+        return Err(Error::AlreadyExists(path.display().to_string()));
+    }
+    fs::write(path, content).map_err(|e| Error::io(path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{code_blocks, emphasized, write_example};
+    use crate::advent_of_code::temp_dir::TempDir;
+    use crate::advent_of_code::Error;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+2   5
+</code></pre>
+<p>In the example above, this is <code>2 + 1 + 0 + 1 + 2 + 5</code>, a total distance of <code><em>11</em></code>!</p>
+<p>Pairs look like <code>a &lt; b &amp;&amp; c</code>, <em>not</em> an answer.</p>
+<pre><code><em>x</em> &gt; y
+</code></pre>
+<p>So, the similarity score is <em><code>31</code></em>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn code_blocks_test() {
+        assert_eq!(code_blocks(PAGE), vec!["3   4\n4   3\n2   5\n", "x > y\n"]);
+        assert!(code_blocks("<pre><code>unclosed").is_empty());
+    }
+
+    #[test]
+    fn emphasized_test() {
+        assert_eq!(emphasized(PAGE), vec!["11", "31"]);
+    }
+
+    #[test]
+    fn write_example_test() {
+        let dir = TempDir::new("example_test");
+        let path = dir.join("day1_1_test.txt");
+        fs::write(&path, "").unwrap();
+        write_example(&path, "3   4\n").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n");
+        let e = write_example(&path, "other").unwrap_err();
+        assert!(matches!(e, Error::AlreadyExists(_)));
+    }
+}
@@ -0,0 +1,361 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+// Grids are addressed by (row, col) while some puzzles give positions as (x, y).
+// A `Point` always stores x (the column) and y (the row, growing downwards) and
+// converting from or to one of the tuple orders has to be spelled out.
+
+/// A position in the plane, `x` is the column and `y` the row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+/// The difference between two points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vec2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn from_xy((x, y): (isize, isize)) -> Self {
+        Self { x, y }
+    }
+
+    pub fn to_xy(self) -> (isize, isize) {
+        (self.x, self.y)
+    }
+
+    pub fn from_row_col((row, col): (isize, isize)) -> Self {
+        Self { x: col, y: row }
+    }
+
+    pub fn to_row_col(self) -> (isize, isize) {
+        (self.y, self.x)
+    }
+
+    /// Converts a (row, col) grid index into a point.
+    pub fn from_grid((row, col): (usize, usize)) -> Self {
+        Self {
+            x: col as isize,
+            y: row as isize,
+        }
+    }
+
+    /// Converts the point into a (row, col) grid index, if neither coordinate is negative.
+    pub fn to_grid(self) -> Option<(usize, usize)> {
+        match self.x >= 0 && self.y >= 0 {
+            true => Some((self.y as usize, self.x as usize)),
+            false => None,
+        }
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        (other - self).manhattan_len()
+    }
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2 { x: 0, y: 0 };
+
+    pub fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn from_xy((x, y): (isize, isize)) -> Self {
+        Self { x, y }
+    }
+
+    pub fn to_xy(self) -> (isize, isize) {
+        (self.x, self.y)
+    }
+
+    pub fn from_row_col((row, col): (isize, isize)) -> Self {
+        Self { x: col, y: row }
+    }
+
+    pub fn to_row_col(self) -> (isize, isize) {
+        (self.y, self.x)
+    }
+
+    pub fn manhattan_len(self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    /// Rotates by 90° clockwise, with y pointing down.
+    pub fn rotate_right(self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// Rotates by 90° counter-clockwise, with y pointing down.
+    pub fn rotate_left(self) -> Self {
+        Self {
+            x: self.y,
+            y: -self.x,
+        }
+    }
+}
+
+impl Add<Vec2> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Vec2) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign<Vec2> for Point {
+    fn add_assign(&mut self, rhs: Vec2) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Vec2> for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Vec2) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign<Vec2> for Point {
+    fn sub_assign(&mut self, rhs: Vec2) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vec2;
+
+    fn sub(self, rhs: Point) -> Self::Output {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, rhs: Vec2) -> Self::Output {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, rhs: Vec2) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, rhs: Vec2) -> Self::Output {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Vec2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Self::Output {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+/// The four directions along the grid axes, north is up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    N,
+    E,
+    S,
+    W,
+}
+
+impl Direction4 {
+    /// All directions, clockwise starting north.
+    pub const ALL: [Direction4; 4] = [Direction4::N, Direction4::E, Direction4::S, Direction4::W];
+
+    /// Reads the arrows `^`, `>`, `v` and `<`.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Direction4::N),
+            '>' => Some(Direction4::E),
+            'v' => Some(Direction4::S),
+            '<' => Some(Direction4::W),
+            _ => None,
+        }
+    }
+
+    /// Turns right (clockwise).
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction4::N => Direction4::E,
+            Direction4::E => Direction4::S,
+            Direction4::S => Direction4::W,
+            Direction4::W => Direction4::N,
+        }
+    }
+
+    /// Turns left (counter-clockwise).
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction4::N => Direction4::W,
+            Direction4::W => Direction4::S,
+            Direction4::S => Direction4::E,
+            Direction4::E => Direction4::N,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// The step of length 1 into this direction.
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Direction4::N => Vec2::new(0, -1),
+            Direction4::E => Vec2::new(1, 0),
+            Direction4::S => Vec2::new(0, 1),
+            Direction4::W => Vec2::new(-1, 0),
+        }
+    }
+}
+
+/// The four axis directions and the four diagonals in between, north is up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    /// All directions, clockwise starting north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::N,
+        Direction8::NE,
+        Direction8::E,
+        Direction8::SE,
+        Direction8::S,
+        Direction8::SW,
+        Direction8::W,
+        Direction8::NW,
+    ];
+
+    /// Turns right by 45°.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns left by 45°.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// The step into this direction, diagonals move one row and one column.
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Direction8::N => Vec2::new(0, -1),
+            Direction8::NE => Vec2::new(1, -1),
+            Direction8::E => Vec2::new(1, 0),
+            Direction8::SE => Vec2::new(1, 1),
+            Direction8::S => Vec2::new(0, 1),
+            Direction8::SW => Vec2::new(-1, 1),
+            Direction8::W => Vec2::new(-1, 0),
+            Direction8::NW => Vec2::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(dir: Direction4) -> Self {
+        match dir {
+            Direction4::N => Direction8::N,
+            Direction4::E => Direction8::E,
+            Direction4::S => Direction8::S,
+            Direction4::W => Direction8::W,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction4, Direction8, Point, Vec2};
+
+    #[test]
+    fn conversion_test() {
+        let p = Point::from_row_col((1, 5));
+        assert_eq!(p, Point::new(5, 1));
+        assert_eq!(p.to_xy(), (5, 1));
+        assert_eq!(p.to_row_col(), (1, 5));
+        assert_eq!(Point::from_grid((2, 3)), Point::from_xy((3, 2)));
+        assert_eq!(Point::new(3, 2).to_grid(), Some((2, 3)));
+        assert_eq!(Point::new(-1, 2).to_grid(), None);
+        assert_eq!(Vec2::from_row_col((-1, 0)), Direction4::N.offset());
+    }
+
+    #[test]
+    fn arithmetic_test() {
+        let a = Point::new(1, 2);
+        let b = Point::new(4, -2);
+        assert_eq!(b - a, Vec2::new(3, -4));
+        assert_eq!(a + (b - a), b);
+        assert_eq!(a - (b - a), Point::new(-2, 6));
+        assert_eq!(Vec2::new(3, -4) * 2, Vec2::new(6, -8));
+        assert_eq!(-Vec2::new(3, -4), Vec2::new(-3, 4));
+        assert_eq!(a.manhattan(b), 7);
+    }
+
+    #[test]
+    fn direction4_test() {
+        assert_eq!(Direction4::N.turn_right(), Direction4::E);
+        assert_eq!(Direction4::N.turn_left(), Direction4::W);
+        assert_eq!(Direction4::E.reverse(), Direction4::W);
+        assert_eq!(Direction4::from_arrow('v'), Some(Direction4::S));
+        for dir in Direction4::ALL {
+            assert_eq!(dir.turn_right().offset(), dir.offset().rotate_right());
+            assert_eq!(dir.turn_left().offset(), dir.offset().rotate_left());
+            assert_eq!(dir.reverse().offset(), -dir.offset());
+        }
+    }
+
+    #[test]
+    fn direction8_test() {
+        assert_eq!(Direction8::NW.turn_right(), Direction8::N);
+        assert_eq!(Direction8::N.turn_left(), Direction8::NW);
+        assert_eq!(Direction8::NE.reverse(), Direction8::SW);
+        for dir in Direction8::ALL {
+            assert_eq!(dir.reverse().offset(), -dir.offset());
+        }
+        for dir in Direction4::ALL {
+            assert_eq!(Direction8::from(dir).offset(), dir.offset());
+        }
+    }
+}
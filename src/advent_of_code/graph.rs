@@ -0,0 +1,533 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// Graph searches that only know the graph through a neighbour function, so they
+// work on a `Grid` as well as on an adjacency map:
+//
+//     graph::bfs([start], |&pos| grid.neighbours4(pos).filter(|&n| grid[n] != '#'))
+
+/// The result of a search: the distance of every reached node and its predecessors
+/// on all shortest paths to it. The start nodes have distance zero.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    distances: HashMap<N, C>,
+    predecessors: HashMap<N, Vec<N>>,
+}
+
+impl<N, C> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord,
+{
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.distances
+    }
+
+    /// The nodes before `node` on its shortest paths, empty for a start node.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// One shortest path from a start to `node`, both included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every shortest path to `node`, there can be exponentially many of them.
+    pub fn all_paths_to(&self, node: &N) -> Vec<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return Vec::new();
+        }
+        // The paths are built backwards from `node`.
+        let mut paths = Vec::new();
+        let mut partial = vec![vec![node.clone()]];
+        while let Some(path) = partial.pop() {
+            let predecessors = self.predecessors(path.last().unwrap());
+            if predecessors.is_empty() {
+                paths.push(path.into_iter().rev().collect());
+                continue;
+            }
+            for previous in predecessors {
+                let mut longer = path.clone();
+                longer.push(previous.clone());
+                partial.push(longer);
+            }
+        }
+        paths
+    }
+
+    /// All nodes that are on at least one shortest path to `node`.
+    pub fn nodes_on_paths_to(&self, node: &N) -> HashSet<N> {
+        let mut seen = HashSet::new();
+        if !self.distances.contains_key(node) {
+            return seen;
+        }
+        let mut todo = vec![node.clone()];
+        while let Some(node) = todo.pop() {
+            if seen.insert(node.clone()) {
+                todo.extend(self.predecessors(&node).iter().cloned());
+            }
+        }
+        seen
+    }
+
+    /// The number of shortest paths to `node`, without listing them.
+    pub fn count_paths_to(&self, node: &N) -> u64 {
+        if !self.distances.contains_key(node) {
+            return 0;
+        }
+        // Nodes on zero-cost edges share their distance, so the predecessors are counted
+        // first by going through them depth first.
+        let mut counts: HashMap<N, u64> = HashMap::new();
+        let mut stack = vec![(node.clone(), false)];
+        while let Some((n, expanded)) = stack.pop() {
+            if counts.contains_key(&n) {
+                continue;
+            }
+            let predecessors = self.predecessors(&n);
+            if !expanded && !predecessors.is_empty() {
+                stack.push((n.clone(), true));
+                stack.extend(predecessors.iter().map(|p| (p.clone(), false)));
+                continue;
+            }
+            let count = match predecessors {
+                [] => 1,
+                predecessors => predecessors.iter().map(|p| counts[p]).sum(),
+            };
+            counts.insert(n, count);
+        }
+        counts[node]
+    }
+}
+
+/// Breadth-first search from all `starts`, every edge has length one.
+pub fn bfs<N, F, I>(starts: impl IntoIterator<Item = N>, mut neighbours: F) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if paths.distances.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        let distance = paths.distances[&node] + 1;
+        for next in neighbours(&node) {
+            match paths.distances.get(&next) {
+                None => {
+                    paths.distances.insert(next.clone(), distance);
+                    paths.predecessors.insert(next.clone(), vec![node.clone()]);
+                    queue.push_back(next);
+                }
+                Some(&d) if d == distance => {
+                    paths
+                        .predecessors
+                        .entry(next)
+                        .or_default()
+                        .push(node.clone());
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    paths
+}
+
+// Dijkstra, or A* with a `heuristic` that is not zero. Stops at the first node that
+// `is_goal` accepts and returns it.
+fn search<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (Paths<N, C>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new();
+    let mut done = HashSet::new();
+    // The heap holds indices into `queued`, so nodes do not have to be `Ord`.
+    let mut queued = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if paths
+            .distances
+            .insert(start.clone(), C::default())
+            .is_none()
+        {
+            heap.push((Reverse(heuristic(&start)), queued.len()));
+            queued.push(start);
+        }
+    }
+
+    while let Some((_, index)) = heap.pop() {
+        let node = queued[index].clone();
+        if !done.insert(node.clone()) {
+            continue;
+        }
+        if is_goal(&node) {
+            return (paths, Some(node));
+        }
+        let distance = paths.distances[&node];
+        for (next, cost) in neighbours(&node) {
+            let candidate = distance + cost;
+            match paths.distances.get(&next) {
+                Some(&d) if d < candidate => continue,
+                Some(&d) if d == candidate => {
+                    // Over a zero-cost edge a settled node, or a start, can be reached again
+                    // at the same cost. Taking it as predecessor would make a loop.
+                    if !done.contains(&next) && paths.predecessors.contains_key(&next) {
+                        paths
+                            .predecessors
+                            .entry(next)
+                            .or_default()
+                            .push(node.clone());
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+            paths.distances.insert(next.clone(), candidate);
+            paths.predecessors.insert(next.clone(), vec![node.clone()]);
+            heap.push((Reverse(candidate + heuristic(&next)), queued.len()));
+            queued.push(next);
+        }
+    }
+    (paths, None)
+}
+
+/// Dijkstra from all `starts` over the whole reachable graph, `neighbours` gives
+/// every next node with the cost to get there. Costs can be zero, but not negative.
+pub fn dijkstra<N, C, F, I>(starts: impl IntoIterator<Item = N>, neighbours: F) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    search(starts, neighbours, |_| C::default(), |_| false).0
+}
+
+/// A* from `start` to the first node that `is_goal` accepts, returning the cost and the path.
+///
+/// The `heuristic` must never overestimate the remaining cost, like the manhattan
+/// distance on a grid.
+pub fn astar<N, C, F, I>(
+    start: N,
+    neighbours: F,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(C, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (paths, goal) = search([start], neighbours, heuristic, is_goal);
+    let goal = goal?;
+    Some((paths.distance(&goal)?, paths.path_to(&goal)?))
+}
+
+/// Counts all paths from `start` to the nodes `is_goal` accepts, not only the shortest ones.
+/// The paths end at the first goal. Panics if the graph has a cycle, the count would be endless.
+pub fn count_paths<N, F, I>(start: N, mut neighbours: F, mut is_goal: impl FnMut(&N) -> bool) -> u64
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut counts: HashMap<N, u64> = HashMap::new();
+    let mut open = HashSet::new();
+    // A node comes back with its successors once they are counted.
+    let mut stack: Vec<(N, Option<Vec<N>>)> = vec![(start.clone(), None)];
+    while let Some((node, successors)) = stack.pop() {
+        match successors {
+            Some(successors) => {
+                let count = successors.iter().map(|n| counts[n]).sum();
+                open.remove(&node);
+                counts.insert(node, count);
+            }
+            None if counts.contains_key(&node) => {}
+            None if is_goal(&node) => {
+                counts.insert(node, 1);
+            }
+            None => {
+                assert!(open.insert(node.clone()), "The graph has a cycle.");
+                let successors: Vec<N> = neighbours(&node).into_iter().collect();
+                let todo: Vec<N> = successors
+                    .iter()
+                    .filter(|n| !counts.contains_key(n))
+                    .cloned()
+                    .collect();
+                stack.push((node, Some(successors)));
+                stack.extend(todo.into_iter().map(|n| (n, None)));
+            }
+        }
+    }
+    counts[&start]
+}
+
+/// Orders `nodes` so that for every edge `(a, b)` between two of them `a` comes before `b`.
+/// Edges to nodes outside of `nodes` are ignored, so a subset of a larger graph can be sorted.
+///
+/// When several nodes could come next the smallest one is taken, so the order is always
+/// the same. If there is no order, the error holds the nodes of a cycle, each one pointing
+/// to the next and the last back to the first.
+pub fn topological_sort<N>(
+    nodes: impl IntoIterator<Item = N>,
+    edges: impl IntoIterator<Item = (N, N)>,
+) -> std::result::Result<Vec<N>, Vec<N>>
+where
+    N: Clone + Ord + Hash,
+{
+    topological_sort_by_key(nodes, edges, N::clone)
+}
+
+/// Like `topological_sort`, but when several nodes could come next the one with the
+/// smallest `key` is taken, and of equal keys the one given first.
+pub fn topological_sort_by_key<N, K: Ord>(
+    nodes: impl IntoIterator<Item = N>,
+    edges: impl IntoIterator<Item = (N, N)>,
+    mut key: impl FnMut(&N) -> K,
+) -> std::result::Result<Vec<N>, Vec<N>>
+where
+    N: Clone + Eq + Hash,
+{
+    // Works on the indices of the nodes, so they only have to be hashable.
+    let mut index: HashMap<N, usize> = HashMap::new();
+    let nodes: Vec<N> = nodes
+        .into_iter()
+        .filter(|n| {
+            let next = index.len();
+            *index.entry(n.clone()).or_insert(next) == next
+        })
+        .collect();
+    let keys: Vec<K> = nodes.iter().map(&mut key).collect();
+    let mut in_degree = vec![0; nodes.len()];
+    let mut successors: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+    for (a, b) in edges {
+        if let (Some(&a), Some(&b)) = (index.get(&a), index.get(&b)) {
+            in_degree[b] += 1;
+            successors[a].push(b);
+        }
+    }
+
+    let mut ready: BinaryHeap<Reverse<(&K, usize)>> = (0..nodes.len())
+        .filter(|&i| in_degree[i] == 0)
+        .map(|i| Reverse((&keys[i], i)))
+        .collect();
+    let mut order = Vec::with_capacity(nodes.len());
+    while let Some(Reverse((_, i))) = ready.pop() {
+        for &next in &successors[i] {
+            in_degree[next] -= 1;
+            if in_degree[next] == 0 {
+                ready.push(Reverse((&keys[next], next)));
+            }
+        }
+        order.push(i);
+    }
+    if order.len() == nodes.len() {
+        return Ok(order.into_iter().map(|i| nodes[i].clone()).collect());
+    }
+
+    // Every node left over has a predecessor that is left over too, following them
+    // backwards has to run into a cycle.
+    let mut predecessor = vec![None; nodes.len()];
+    for (a, next) in successors.iter().enumerate() {
+        for &b in next {
+            if in_degree[a] > 0 && in_degree[b] > 0 {
+                predecessor[b] = Some(a);
+            }
+        }
+    }
+    let first = (0..nodes.len())
+        .filter(|&i| in_degree[i] > 0)
+        .min_by_key(|&i| (&keys[i], i))
+        .unwrap();
+    let mut walk = vec![first];
+    loop {
+        let previous = predecessor[*walk.last().unwrap()].unwrap();
+        if let Some(start) = walk.iter().position(|&i| i == previous) {
+            let cycle = walk[start..].iter().rev().map(|&i| nodes[i].clone());
+            return Err(cycle.collect());
+        }
+        walk.push(previous);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{astar, bfs, count_paths, dijkstra, topological_sort, topological_sort_by_key};
+    use crate::advent_of_code::parse::char_grid;
+    use crate::advent_of_code::Grid;
+
+    const MAZE: [&str; 5] = ["S..#.", ".#...", ".#.#.", "...#E", "##..."];
+
+    fn open(grid: &Grid<char>, pos: (usize, usize)) -> Vec<(usize, usize)> {
+        grid.neighbours4(pos).filter(|&n| grid[n] != '#').collect()
+    }
+
+    #[test]
+    fn bfs_test() {
+        let grid = char_grid(&MAZE.join("\n")).unwrap();
+        let paths = bfs([(0, 0)], |&pos| open(&grid, pos));
+        assert_eq!(paths.distance(&(3, 4)), Some(7));
+        assert_eq!(paths.distance(&(0, 3)), None);
+        let path = paths.path_to(&(3, 4)).unwrap();
+        assert_eq!(path.len(), 8);
+        assert_eq!((path[0], path[7]), ((0, 0), (3, 4)));
+
+        // An open 3x3 square has 6 shortest paths between opposite corners.
+        let square = Grid::filled(3, 3, '.');
+        let paths = bfs([(0, 0)], |&pos| open(&square, pos));
+        assert_eq!(paths.all_paths_to(&(2, 2)).len(), 6);
+        assert_eq!(paths.count_paths_to(&(2, 2)), 6);
+        assert_eq!(paths.nodes_on_paths_to(&(2, 2)).len(), 9);
+        assert_eq!(paths.count_paths_to(&(0, 2)), 1);
+    }
+
+    #[test]
+    fn dijkstra_test() {
+        let edges = HashMap::from([
+            ("a", vec![("b", 7), ("c", 9), ("f", 14)]),
+            ("b", vec![("c", 10), ("d", 15)]),
+            ("c", vec![("d", 11), ("f", 2)]),
+            ("d", vec![("e", 6)]),
+            ("f", vec![("e", 9)]),
+        ]);
+        let neighbours = |node: &&str| edges.get(node).cloned().unwrap_or_default();
+        let paths = dijkstra(["a"], neighbours);
+        assert_eq!(paths.distance(&"e"), Some(20));
+        assert_eq!(paths.path_to(&"e").unwrap(), ["a", "c", "f", "e"]);
+        // a-b-d costs 22, a-c-d is the only shortest path.
+        assert_eq!(paths.distance(&"d"), Some(20));
+        assert_eq!(paths.predecessors(&"d"), ["c"]);
+        assert_eq!(paths.count_paths_to(&"d"), 1);
+    }
+
+    #[test]
+    fn dijkstra_zero_cost_test() {
+        // a and b are the same distance apart both ways, c can be reached from both.
+        let edges = HashMap::from([
+            ("a", vec![("b", 0), ("c", 1)]),
+            ("b", vec![("a", 0), ("c", 1)]),
+        ]);
+        let neighbours = |node: &&str| edges.get(node).cloned().unwrap_or_default();
+        let paths = dijkstra(["a"], neighbours);
+        assert_eq!(paths.distance(&"b"), Some(0));
+        assert_eq!(paths.predecessors(&"a"), [] as [&str; 0]);
+        assert_eq!(paths.predecessors(&"b"), ["a"]);
+        assert_eq!(paths.path_to(&"c").unwrap(), ["a", "c"]);
+        assert_eq!(paths.all_paths_to(&"c").len(), 2);
+        assert_eq!(paths.count_paths_to(&"c"), 2);
+        assert_eq!(paths.count_paths_to(&"z"), 0);
+
+        // Both are starts, neither is on a path to the other.
+        let paths = dijkstra(["a", "b"], neighbours);
+        assert_eq!(paths.predecessors(&"b"), [] as [&str; 0]);
+        assert_eq!(paths.count_paths_to(&"c"), 2);
+    }
+
+    #[test]
+    fn astar_test() {
+        let grid = char_grid(&MAZE.join("\n")).unwrap();
+        let goal = (3, 4);
+        let (cost, path) = astar(
+            (0, 0),
+            |&pos| open(&grid, pos).into_iter().map(|n| (n, 1)),
+            |&(row, col): &(usize, usize)| row.abs_diff(goal.0) + col.abs_diff(goal.1),
+            |&pos| pos == goal,
+        )
+        .unwrap();
+        assert_eq!(cost, 7);
+        assert_eq!(path.len(), 8);
+        let walled = astar((0, 0), |_| Vec::new(), |_| 0, |&pos| pos == goal);
+        assert_eq!(walled, None);
+    }
+
+    #[test]
+    fn count_paths_test() {
+        // Every path through the 3x3 square going only right or down.
+        let paths = count_paths(
+            (0, 0),
+            |&(row, col): &(usize, usize)| {
+                [(row + 1, col), (row, col + 1)]
+                    .into_iter()
+                    .filter(|&(r, c)| r < 3 && c < 3)
+            },
+            |&pos| pos == (2, 2),
+        );
+        assert_eq!(paths, 6);
+        assert_eq!(count_paths(0, |_| Vec::new(), |&n| n == 1), 0);
+    }
+
+    #[test]
+    fn topological_sort_test() {
+        let edges = [(1, 2), (2, 3), (1, 3), (5, 3), (3, 9)];
+        assert_eq!(topological_sort([3, 5, 2, 1], edges), Ok(vec![1, 2, 5, 3]));
+        // 9 is not among the nodes, so its edge does not count.
+        assert_eq!(topological_sort([9, 4, 3], edges), Ok(vec![3, 4, 9]));
+
+        let cyclic = [(1, 2), (2, 3), (3, 1), (0, 1), (3, 4)];
+        let cycle = topological_sort(0..5, cyclic).unwrap_err();
+        assert_eq!(cycle.len(), 3);
+        for (i, node) in cycle.iter().enumerate() {
+            let next = cycle[(i + 1) % cycle.len()];
+            assert!(cyclic.contains(&(*node, next)));
+        }
+        // Without 3 the rest is fine.
+        assert_eq!(topological_sort([0, 1, 2, 4], cyclic), Ok(vec![0, 1, 2, 4]));
+    }
+
+    #[test]
+    fn topological_sort_by_key_test() {
+        let edges = [(1, 2), (2, 3), (1, 3), (5, 3), (3, 9)];
+        // The largest node first instead of the smallest.
+        let largest = |&n: &i32| -n;
+        assert_eq!(
+            topological_sort_by_key([3, 5, 2, 1], edges, largest),
+            Ok(vec![5, 1, 2, 3])
+        );
+        // Equal keys keep the given order.
+        assert_eq!(
+            topological_sort_by_key([4, 9, 6, 3], edges, |_| 0),
+            Ok(vec![4, 6, 3, 9])
+        );
+        // Shorter words first, b and c both have to come before a.
+        let words = [("b", "a"), ("c", "a")];
+        assert_eq!(
+            topological_sort_by_key(["a", "bb", "c", "b"], words, |w| w.len()),
+            Ok(vec!["c", "b", "a", "bb"])
+        );
+        let cycle = topological_sort_by_key(["x", "y"], [("x", "y"), ("y", "x")], |w| w.len());
+        assert_eq!(cycle, Err(vec!["y", "x"]));
+    }
+}
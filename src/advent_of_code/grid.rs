@@ -0,0 +1,330 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use super::geometry::{Direction4, Direction8};
+
+/// A rectangular grid stored in a flat buffer, addressed by (row, col).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from cells in row-major order.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "A {width}x{height} grid needs {} cells.",
+            width * height
+        );
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Creates a grid with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_within_bound(&self, pos: (isize, isize)) -> bool {
+        pos.0 >= 0 && pos.0 < self.height as isize && pos.1 >= 0 && pos.1 < self.width as isize
+    }
+
+    /// Turns a signed position into a valid index, if it is on the grid.
+    pub fn check_bound(&self, pos: (isize, isize)) -> Option<(usize, usize)> {
+        match self.is_within_bound(pos) {
+            true => Some((pos.0 as usize, pos.1 as usize)),
+            false => None,
+        }
+    }
+
+    /// Moves from `pos` by `offset`, if the result is still on the grid.
+    pub fn offset(&self, pos: (usize, usize), offset: (isize, isize)) -> Option<(usize, usize)> {
+        self.check_bound((pos.0 as isize + offset.0, pos.1 as isize + offset.1))
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        match pos.0 < self.height && pos.1 < self.width {
+            true => self.cells.get(pos.0 * self.width + pos.1),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        match pos.0 < self.height && pos.1 < self.width {
+            true => self.cells.get_mut(pos.0 * self.width + pos.1),
+            false => None,
+        }
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.height).flat_map(move |row| (0..self.width).map(move |col| (row, col)))
+    }
+
+    /// All cells with their position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| ((i / self.width, i % self.width), cell))
+    }
+
+    /// The first position holding `value`, in row-major order.
+    pub fn find_first(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    /// All positions holding `value`, in row-major order.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    #[deprecated(note = "use `find_all`")]
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.find_all(value)
+    }
+
+    /// The first position holding any of `values`, together with the value found there.
+    pub fn find_any_of(&self, values: &[T]) -> Option<((usize, usize), T)>
+    where
+        T: PartialEq + Clone,
+    {
+        self.iter()
+            .find(|(_, cell)| values.contains(cell))
+            .map(|(pos, cell)| (pos, cell.clone()))
+    }
+
+    /// The up to 4 neighbours above, right, below and left of `pos`.
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction4::ALL
+            .into_iter()
+            .filter_map(move |dir| self.offset(pos, dir.offset().to_row_col()))
+    }
+
+    /// The up to 8 neighbours of `pos`, including the diagonal ones, clockwise starting above.
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.offset(pos, dir.offset().to_row_col()))
+    }
+
+    /// Walks from `start` (included) in steps of `step` until leaving the grid.
+    pub fn ray(
+        &self,
+        start: (usize, usize),
+        step: (isize, isize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        std::iter::successors(self.get(start).map(|_| start), move |&pos| {
+            self.offset(pos, step)
+        })
+    }
+
+    pub fn row(&self, row: usize) -> impl Iterator<Item = &T> {
+        self.cells[row * self.width..(row + 1) * self.width].iter()
+    }
+
+    // Like `row`, a column outside of the grid is a bug in the caller.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            col < self.width,
+            "Column {col} is outside of a grid with {} columns.",
+            self.width
+        );
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.height).map(move |row| self.row(row))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    /// All diagonals running down-right, starting with the one in the bottom left corner.
+    /// A grid without columns has none.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height)
+            .rev()
+            .filter(|_| self.width > 0)
+            .map(|row| (row, 0))
+            .chain((1..self.width).map(|col| (0, col)));
+        starts.map(move |start| self.ray(start, (1, 1)).map(move |pos| &self[pos]))
+    }
+
+    /// All anti-diagonals running down-left, starting with the one in the top left corner.
+    /// A grid without columns has none.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.width).map(|col| (0, col)).chain(
+            (1..self.height)
+                .filter(|_| self.width > 0)
+                .map(|row| (row, self.width - 1)),
+        );
+        starts.map(move |start| self.ray(start, (1, -1)).map(move |pos| &self[pos]))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &Self::Output {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "Position {pos:?} is outside of the {}x{} grid.",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("Position {pos:?} is outside of the {width}x{height} grid."))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::advent_of_code::parse::char_grid;
+
+    fn grid() -> Grid<char> {
+        // abc
+        // def
+        char_grid("abc\ndef").unwrap()
+    }
+
+    #[test]
+    fn index_test() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn bounds_test() {
+        let grid = grid();
+        assert_eq!(grid.check_bound((1, 2)), Some((1, 2)));
+        assert_eq!(grid.check_bound((-1, 2)), None);
+        assert_eq!(grid.check_bound((2, 0)), None);
+        assert_eq!(grid.offset((0, 0), (1, 1)), Some((1, 1)));
+        assert_eq!(grid.offset((0, 0), (0, -1)), None);
+    }
+
+    #[test]
+    fn neighbours_test() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours8((0, 1)).collect::<Vec<_>>(),
+            vec![(0, 2), (1, 2), (1, 1), (1, 0), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn find_test() {
+        let grid = char_grid("#.#\n.#.").unwrap();
+        assert_eq!(
+            grid.find_all(&'#').collect::<Vec<_>>(),
+            vec![(0, 0), (0, 2), (1, 1)]
+        );
+        assert_eq!(grid.find_first(&'.'), Some((0, 1)));
+        assert_eq!(grid.find_first(&'x'), None);
+        assert_eq!(grid.find_any_of(&['x', '.']), Some(((0, 1), '.')));
+        assert_eq!(grid.find_any_of(&['x', 'y']), None);
+
+        #[allow(deprecated)]
+        let positions = grid.positions_of(&'#').collect::<Vec<_>>();
+        assert_eq!(positions, vec![(0, 0), (0, 2), (1, 1)]);
+    }
+
+    #[test]
+    fn lines_test() {
+        let grid = grid();
+        let collect = |it: &mut dyn Iterator<Item = &char>| it.collect::<String>();
+        assert_eq!(collect(&mut grid.row(1)), "def");
+        assert_eq!(collect(&mut grid.column(2)), "cf");
+        assert_eq!(
+            grid.diagonals()
+                .map(|mut d| collect(&mut d))
+                .collect::<Vec<_>>(),
+            vec!["d", "ae", "bf", "c"]
+        );
+        assert_eq!(
+            grid.anti_diagonals()
+                .map(|mut d| collect(&mut d))
+                .collect::<Vec<_>>(),
+            vec!["a", "bd", "ce", "f"]
+        );
+        assert_eq!(
+            grid.ray((0, 0), (0, 1))
+                .map(|pos| grid[pos])
+                .collect::<String>(),
+            "abc"
+        );
+
+        let narrow = Grid::<char>::new(0, 2, Vec::new());
+        assert_eq!(narrow.diagonals().count(), 0);
+        assert_eq!(narrow.anti_diagonals().count(), 0);
+        assert_eq!(narrow.columns().count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn column_outside_test() {
+        let _ = grid().column(3);
+    }
+}
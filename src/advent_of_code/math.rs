@@ -0,0 +1,194 @@
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// The signed integer types the helpers work with.
+pub trait Int:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn rem_euclid(self, modulus: Self) -> Self;
+    fn abs(self) -> Self;
+}
+
+macro_rules! int {
+    ($($t:ty),*) => {
+        $(impl Int for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn rem_euclid(self, modulus: Self) -> Self {
+                <$t>::rem_euclid(self, modulus)
+            }
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+        })*
+    };
+}
+int!(i8, i16, i32, i64, i128, isize);
+
+/// `a` mod `m` in `0..|m|`, also for a negative `a`, unlike `%`.
+pub fn modulo<T: Int>(a: T, m: T) -> T {
+    a.rem_euclid(m)
+}
+
+/// The greatest common divisor, never negative. `gcd(0, 0)` is 0.
+pub fn gcd<T: Int>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, never negative. It is 0 if one of them is 0.
+pub fn lcm<T: Int>(a: T, b: T) -> T {
+    match a == T::ZERO || b == T::ZERO {
+        true => T::ZERO,
+        false => (a / gcd(a, b) * b).abs(),
+    }
+}
+
+/// The gcd of all numbers, 0 for none.
+pub fn gcd_all<T: Int>(numbers: impl IntoIterator<Item = T>) -> T {
+    numbers.into_iter().fold(T::ZERO, gcd)
+}
+
+/// The lcm of all numbers, like the step where all periods line up again. 1 for none.
+pub fn lcm_all<T: Int>(numbers: impl IntoIterator<Item = T>) -> T {
+    numbers.into_iter().fold(T::ONE, lcm)
+}
+
+/// Returns `(g, x, y)` with `a * x + b * y == g`, where `g` is the gcd of `a` and `b`.
+pub fn extended_gcd<T: Int>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    match old_r < T::ZERO {
+        true => (-old_r, -old_x, -old_y),
+        false => (old_r, old_x, old_y),
+    }
+}
+
+/// The `x` in `0..m` with `a * x` being 1 mod `m`, if `a` and `m` are coprime.
+/// `None` for a modulus of 0.
+pub fn mod_inverse<T: Int>(a: T, m: T) -> Option<T> {
+    if m == T::ZERO {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(modulo(a, m), m.abs());
+    (g == T::ONE).then(|| modulo(x, m))
+}
+
+/// Solves `x = r mod m` for all `(r, m)` pairs, the moduli do not have to be coprime.
+///
+/// Returns the smallest non-negative `x` and the lcm of the moduli, all solutions are
+/// `x + k * lcm`. `None` if the congruences contradict each other or a modulus is 0.
+/// The lcm of the moduli squared has to fit into `T`.
+pub fn crt<T: Int>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    congruences
+        .into_iter()
+        .try_fold((T::ZERO, T::ONE), |(x, m), (r, n)| {
+            let n = n.abs();
+            if n == T::ZERO {
+                return None;
+            }
+            let (g, p, _) = extended_gcd(m, n);
+            let diff = r - x;
+            if diff % g != T::ZERO {
+                return None;
+            }
+            // x + m * k is r mod n for k = diff / g * p mod n / g.
+            let n_g = n / g;
+            let k = modulo(modulo(diff / g, n_g) * modulo(p, n_g), n_g);
+            let l = m * n_g;
+            Some((modulo(x + m * k, l), l))
+        })
+}
+
+/// The integer solution of `a * x + b * y = e` and `c * x + d * y = f`, given as
+/// `[[a, b], [c, d]]` and `[e, f]`. `None` if there is no single solution or it is not
+/// made of integers.
+pub fn solve_2x2<T: Int>([[a, b], [c, d]]: [[T; 2]; 2], [e, f]: [T; 2]) -> Option<(T, T)> {
+    // Cramer's rule.
+    let det = a * d - b * c;
+    if det == T::ZERO {
+        return None;
+    }
+    let x = e * d - b * f;
+    let y = a * f - e * c;
+    (x % det == T::ZERO && y % det == T::ZERO).then(|| (x / det, y / det))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{crt, extended_gcd, gcd, gcd_all, lcm, lcm_all, mod_inverse, modulo, solve_2x2};
+
+    #[test]
+    fn modulo_test() {
+        assert_eq!(modulo(-7, 5), 3);
+        assert_eq!(modulo(-7_isize, -5), 3);
+        assert_eq!(-7 % 5, -2);
+        assert_eq!(modulo(12_i8, 5), 2);
+    }
+
+    #[test]
+    fn gcd_test() {
+        assert_eq!(gcd(12, -18), 6);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(lcm(4_i64, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(gcd_all([12, 18, 27]), 3);
+        assert_eq!(lcm_all([101_i64, 103]), 10403);
+        assert_eq!(lcm_all(Vec::<i32>::new()), 1);
+
+        for (a, b) in [(240, 46), (-7, 3), (0, 5), (5, 0)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(4, 8), None);
+        assert_eq!(mod_inverse(3, 0), None);
+    }
+
+    #[test]
+    fn crt_test() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Not coprime, but compatible: 10 mod 4 is 2, 10 mod 6 is 4.
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(-1_i64, 101), (-1, 103)]), Some((10402, 10403)));
+        assert_eq!(crt(Vec::<(i32, i32)>::new()), Some((0, 1)));
+        assert_eq!(crt([(2, 3), (1, 0)]), None);
+    }
+
+    #[test]
+    fn solve_2x2_test() {
+        // 94a + 22b = 8400, 34a + 67b = 5400
+        assert_eq!(
+            solve_2x2([[94, 22], [34, 67]], [8400, 5400]),
+            Some((80, 40))
+        );
+        assert_eq!(solve_2x2([[26, 67], [66, 21]], [12748, 12176]), None);
+        assert_eq!(solve_2x2([[1, 2], [2, 4]], [3, 6]), None);
+    }
+}
@@ -0,0 +1,204 @@
+use super::{Error, Result};
+
+// Transforms of plain `Vec<Vec<T>>` matrices, indexed `[row][col]`. All of them check
+// first that the rows have the same length, an empty matrix is fine.
+
+fn width<T>(matrix: &[Vec<T>]) -> Result<usize> {
+    let width = matrix.first().map_or(0, Vec::len);
+    match matrix.iter().position(|row| row.len() != width) {
+        Some(row) => Err(Error::parse(
+            row + 1,
+            1,
+            format!(
+                "row has {} cells, the ones before have {width}",
+                matrix[row].len()
+            ),
+        )),
+        None => Ok(width),
+    }
+}
+
+// The columns in the given order, each one read from the rows in the given order.
+fn columns<T: Clone>(
+    matrix: &[Vec<T>],
+    cols: impl Iterator<Item = usize>,
+    bottom_up: bool,
+) -> Vec<Vec<T>> {
+    cols.map(|col| {
+        let cells = matrix.iter().map(|row| row[col].clone());
+        match bottom_up {
+            true => cells.rev().collect(),
+            false => cells.collect(),
+        }
+    })
+    .collect()
+}
+
+/// Rows become columns, `[row][col]` moves to `[col][row]`.
+pub fn transpose<T: Clone>(matrix: &[Vec<T>]) -> Result<Vec<Vec<T>>> {
+    let width = width(matrix)?;
+    Ok(columns(matrix, 0..width, false))
+}
+
+/// Rotates by 90° clockwise, the first column read bottom-up becomes the first row.
+pub fn rotate_cw<T: Clone>(matrix: &[Vec<T>]) -> Result<Vec<Vec<T>>> {
+    let width = width(matrix)?;
+    Ok(columns(matrix, 0..width, true))
+}
+
+/// Rotates by 90° counter-clockwise, the last column becomes the first row.
+pub fn rotate_ccw<T: Clone>(matrix: &[Vec<T>]) -> Result<Vec<Vec<T>>> {
+    let width = width(matrix)?;
+    Ok(columns(matrix, (0..width).rev(), false))
+}
+
+/// Mirrors left to right, every row is reversed.
+pub fn flip_horizontal<T: Clone>(matrix: &[Vec<T>]) -> Result<Vec<Vec<T>>> {
+    width(matrix)?;
+    Ok(matrix
+        .iter()
+        .map(|row| row.iter().rev().cloned().collect())
+        .collect())
+}
+
+/// Mirrors top to bottom, the order of the rows is reversed.
+pub fn flip_vertical<T: Clone>(matrix: &[Vec<T>]) -> Result<Vec<Vec<T>>> {
+    width(matrix)?;
+    Ok(matrix.iter().rev().cloned().collect())
+}
+
+// The cells from `start` on, moving one row down and `step` columns each time.
+fn line<T: Clone>(matrix: &[Vec<T>], start: (usize, usize), step: isize) -> Vec<T> {
+    (start.0..matrix.len())
+        .map_while(|row| {
+            let col = start.1 as isize + (row - start.0) as isize * step;
+            matrix[row].get(usize::try_from(col).ok()?).cloned()
+        })
+        .collect()
+}
+
+/// All diagonals running down-right, starting with the one in the bottom left corner,
+/// like `Grid::diagonals`.
+pub fn diagonals<T: Clone>(matrix: &[Vec<T>]) -> Result<impl Iterator<Item = Vec<T>> + '_> {
+    let width = width(matrix)?;
+    let starts = (0..matrix.len())
+        .rev()
+        .map(|row| (row, 0))
+        .chain((1..width).map(|col| (0, col)));
+    Ok(starts
+        .map(move |start| line(matrix, start, 1))
+        .filter(|line| !line.is_empty()))
+}
+
+/// All anti-diagonals running down-left, starting with the one in the top left corner,
+/// like `Grid::anti_diagonals`.
+pub fn anti_diagonals<T: Clone>(matrix: &[Vec<T>]) -> Result<impl Iterator<Item = Vec<T>> + '_> {
+    let width = width(matrix)?;
+    // Without columns there is no last one to start from.
+    let starts = (0..width).map(|col| (0, col)).chain(
+        (1..matrix.len())
+            .filter(move |_| width > 0)
+            .map(move |row| (row, width - 1)),
+    );
+    Ok(starts
+        .map(move |start| line(matrix, start, -1))
+        .filter(|line| !line.is_empty()))
+}
+
+pub fn lines_to_matrix(lines: &[String]) -> Vec<Vec<char>> {
+    lines
+        .iter()
+        .map(|line| line.chars().collect::<Vec<char>>())
+        .collect::<Vec<Vec<char>>>()
+}
+
+/// All positions holding `value`, row by row. Unlike the transforms this works on rows of
+/// any length.
+pub fn find_all<'a, T: PartialEq>(
+    matrix: &'a [Vec<T>],
+    value: &'a T,
+) -> impl Iterator<Item = (usize, usize)> + 'a {
+    matrix.iter().enumerate().flat_map(move |(row, cells)| {
+        cells
+            .iter()
+            .enumerate()
+            .filter(move |(_, cell)| *cell == value)
+            .map(move |(col, _)| (row, col))
+    })
+}
+
+/// The first position holding `value`, see `find_all`.
+pub fn find_first<T: PartialEq>(matrix: &[Vec<T>], value: &T) -> Option<(usize, usize)> {
+    find_all(matrix, value).next()
+}
+
+/// The first position holding any of `values`, together with the value found there.
+pub fn find_any_of<T: PartialEq + Clone>(
+    matrix: &[Vec<T>],
+    values: &[T],
+) -> Option<((usize, usize), T)> {
+    matrix.iter().enumerate().find_map(|(row, cells)| {
+        let col = cells.iter().position(|cell| values.contains(cell))?;
+        Some(((row, col), cells[col].clone()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        anti_diagonals, diagonals, find_all, find_any_of, find_first, flip_horizontal,
+        flip_vertical, rotate_ccw, rotate_cw, transpose,
+    };
+    use crate::advent_of_code::Error;
+
+    fn m(rows: &[&str]) -> Vec<Vec<char>> {
+        rows.iter().map(|row| row.chars().collect()).collect()
+    }
+
+    #[test]
+    fn transform_test() {
+        let matrix = m(&["abc", "def"]);
+        assert_eq!(transpose(&matrix).unwrap(), m(&["ad", "be", "cf"]));
+        assert_eq!(rotate_cw(&matrix).unwrap(), m(&["da", "eb", "fc"]));
+        assert_eq!(rotate_ccw(&matrix).unwrap(), m(&["cf", "be", "ad"]));
+        assert_eq!(flip_horizontal(&matrix).unwrap(), m(&["cba", "fed"]));
+        assert_eq!(flip_vertical(&matrix).unwrap(), m(&["def", "abc"]));
+        // Four turns are no turn at all.
+        let turned = (0..4).fold(matrix.clone(), |acc, _| rotate_cw(&acc).unwrap());
+        assert_eq!(turned, matrix);
+
+        let empty: Vec<Vec<char>> = Vec::new();
+        assert_eq!(transpose(&empty).unwrap(), empty);
+        let e = rotate_cw(&m(&["abc", "de"])).unwrap_err();
+        assert!(matches!(e, Error::Parse { line: 2, .. }));
+    }
+
+    #[test]
+    fn diagonals_test() {
+        let matrix = m(&["abc", "def"]);
+        let down: Vec<_> = diagonals(&matrix).unwrap().collect();
+        assert_eq!(down, m(&["d", "ae", "bf", "c"]));
+        let anti: Vec<_> = anti_diagonals(&matrix).unwrap().collect();
+        assert_eq!(anti, m(&["a", "bd", "ce", "f"]));
+        assert!(diagonals(&m(&["ab", "c"])).is_err());
+        assert_eq!(anti_diagonals(&Vec::<Vec<u8>>::new()).unwrap().count(), 0);
+        let narrow: Vec<Vec<u8>> = vec![vec![], vec![]];
+        assert_eq!(diagonals(&narrow).unwrap().count(), 0);
+        assert_eq!(anti_diagonals(&narrow).unwrap().count(), 0);
+        assert_eq!(transpose(&narrow).unwrap(), Vec::<Vec<u8>>::new());
+    }
+
+    #[test]
+    fn find_test() {
+        let matrix = m(&["#.#", ".#", "#"]);
+        assert_eq!(
+            find_all(&matrix, &'#').collect::<Vec<_>>(),
+            vec![(0, 0), (0, 2), (1, 1), (2, 0)]
+        );
+        assert_eq!(find_first(&matrix, &'.'), Some((0, 1)));
+        assert_eq!(find_first(&matrix, &'x'), None);
+        assert_eq!(find_any_of(&matrix, &['x', '.']), Some(((0, 1), '.')));
+        assert_eq!(find_any_of(&matrix, &['.', '#']), Some(((0, 0), '#')));
+        assert_eq!(find_any_of(&matrix, &['x', 'y']), None);
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// A recursive function that remembers its results, for counting problems where the
+/// same arguments come up over and over.
+///
+/// The function gets the memo itself to recurse through:
+///
+/// ```
+/// # use aoc_2024::advent_of_code::Memo;
+/// let mut fib = Memo::new(|memo: &mut Memo<u64, u64>, n| match n {
+///     0 | 1 => n,
+///     n => memo.get(n - 1) + memo.get(n - 2),
+/// });
+/// assert_eq!(fib.get(50), 12586269025);
+/// ```
+pub struct Memo<'a, K, V> {
+    cache: HashMap<K, V>,
+    f: Recursive<'a, K, V>,
+}
+
+// Shared, so it can be called while the memo is borrowed mutably.
+type Recursive<'a, K, V> = Rc<dyn Fn(&mut Memo<'a, K, V>, K) -> V + 'a>;
+
+impl<'a, K, V> Memo<'a, K, V>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+{
+    pub fn new(f: impl Fn(&mut Memo<'a, K, V>, K) -> V + 'a) -> Self {
+        Self {
+            cache: HashMap::new(),
+            f: Rc::new(f),
+        }
+    }
+
+    /// The result for `key`, computed only the first time.
+    pub fn get(&mut self, key: K) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+        let f = Rc::clone(&self.f);
+        let value = f(self, key.clone());
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// The number of remembered results.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Memo;
+    use crate::advent_of_code::Digits;
+
+    #[test]
+    fn fibonacci_test() {
+        let mut fib = Memo::new(|memo: &mut Memo<u64, u64>, n| match n {
+            0 | 1 => n,
+            n => memo.get(n - 1) + memo.get(n - 2),
+        });
+        assert_eq!(fib.get(90), 2880067194370816120);
+        assert_eq!(fib.len(), 91);
+    }
+
+    #[test]
+    fn captures_test() {
+        // The stones of day 11, counted instead of listed.
+        let factor = 2024;
+        let mut stones = Memo::new(|memo: &mut Memo<(u64, u32), u64>, (value, blinks)| {
+            if blinks == 0 {
+                return 1;
+            }
+            match value.digit_count() {
+                _ if value == 0 => memo.get((1, blinks - 1)),
+                n if n % 2 == 0 => {
+                    let (left, right) = value.split_digits_at(n / 2).unwrap();
+                    memo.get((left, blinks - 1)) + memo.get((right, blinks - 1))
+                }
+                _ => memo.get((value * factor, blinks - 1)),
+            }
+        });
+        let mut count = |blinks| {
+            [125, 17]
+                .map(|v| stones.get((v, blinks)))
+                .iter()
+                .sum::<u64>()
+        };
+        assert_eq!(count(6), 22);
+        assert_eq!(count(25), 55312);
+    }
+}
@@ -1,39 +1,190 @@
 use std::collections::VecDeque;
-use std::fs::File;
+use std::fmt;
+use std::fs::{self, File};
+use std::hash::{Hash, Hasher};
+use std::hint::black_box;
 use std::io::BufRead;
+use std::ops::Index;
 use std::path::Path;
+use std::time::Instant;
 
-pub mod aoc {}
-#[allow(dead_code)]
+pub mod answers;
+pub mod bag;
+pub mod bench;
+pub mod client;
+pub mod cycle;
+pub mod digits;
+pub mod error;
+pub mod extract;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod math;
+pub mod matrix;
+pub mod memo;
+pub mod parse;
+pub mod scaffold;
+pub mod submit;
+#[cfg(test)]
+pub(crate) mod temp_dir;
+
+pub use bag::Bag;
+pub use bench::PhaseTimes;
+pub use digits::Digits;
+pub use error::{Error, Result};
+pub use geometry::{Direction4, Direction8, Point, Vec2};
+pub use grid::Grid;
+pub use matrix::{lines_to_matrix, transpose};
+pub use memo::Memo;
+
+/// The answer to one part of a puzzle, mostly a number but sometimes text like "7,6,1,5,3,1,4,2,6".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Number(n as i128)
+            }
+        })*
+    };
+}
+answer_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// The common interface of all days: the input is parsed once and both parts are solved on it.
+pub trait Solution {
+    type Input;
+
+    /// Malformed input is an error, not a panic, so the runner can say what is wrong with it.
+    fn parse(data: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    // Days where only the first half is solved keep this default.
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    /// The examples of some days need other settings than the real input, like the smaller
+    /// grid of day 14. Those days parse them here.
+    fn parse_example(data: &str) -> Result<Self::Input> {
+        Self::parse(data)
+    }
+
+    /// Solves the given part (1 or 2) on the parsed input.
+    fn part(input: &Self::Input, part: u8) -> Answer {
+        match part {
+            1 => Self::part1(input),
+            2 => Self::part2(input),
+            _ => panic!("There is no part {part}, only 1 and 2."),
+        }
+    }
+
+    /// Parses the raw puzzle input and solves the given part (1 or 2) on it.
+    fn solve(data: &str, part: u8) -> Result<Answer> {
+        Ok(Self::part(&Self::parse(data)?, part))
+    }
+
+    /// Like `solve`, for an example.
+    fn solve_example(data: &str, part: u8) -> Result<Answer> {
+        Ok(Self::part(&Self::parse_example(data)?, part))
+    }
+
+    /// Parses and solves both parts once, timing each phase on its own.
+    fn time_phases(data: &str) -> Result<PhaseTimes> {
+        time_phases_with::<Self>(Self::parse, data)
+    }
+
+    /// Like `time_phases`, for an example.
+    fn time_example_phases(data: &str) -> Result<PhaseTimes> {
+        time_phases_with::<Self>(Self::parse_example, data)
+    }
+}
+
+fn time_phases_with<S: Solution + ?Sized>(
+    parse: fn(&str) -> Result<S::Input>,
+    data: &str,
+) -> Result<PhaseTimes> {
+    let start = Instant::now();
+    let input = black_box(parse(data)?);
+    let parse = start.elapsed();
+
+    let mut parts = [None; 2];
+    for (i, part) in [S::part1, S::part2].into_iter().enumerate() {
+        let start = Instant::now();
+        let answer = black_box(part(&input));
+        let elapsed = start.elapsed();
+        if answer != Answer::Unsolved {
+            parts[i] = Some(elapsed);
+        }
+    }
+    Ok(PhaseTimes { parse, parts })
+}
 pub struct Reader {}
-#[allow(dead_code)]
 impl Reader {
     // Returns an Iterator to the Reader of the lines of the file.
-    pub fn read_lines<P>(filename: P) -> std::io::Result<std::io::Lines<std::io::BufReader<File>>>
+    pub fn read_lines<P>(filename: P) -> Result<std::io::Lines<std::io::BufReader<File>>>
     where
         P: AsRef<Path>,
     {
-        let file = File::open(filename)?;
+        let file = File::open(&filename).map_err(|e| Error::io(filename.as_ref(), e))?;
         Ok(std::io::BufReader::new(file).lines())
     }
 
-    pub fn read_file(filename: &str) -> std::io::Result<Vec<String>> {
-        let org_data = match Reader::read_lines(filename) {
-            Ok(lines) => lines.collect::<Result<_, _>>().unwrap(),
-            Err(e) => panic!("Error opening file {}: {}", filename, e),
-        };
-        Ok(org_data)
+    pub fn read_file<P>(filename: P) -> Result<Vec<String>>
+    where
+        P: AsRef<Path>,
+    {
+        Reader::read_lines(&filename)?
+            .collect::<std::io::Result<_>>()
+            .map_err(|e| Error::io(filename.as_ref(), e))
+    }
+
+    pub fn read_to_string<P>(filename: P) -> Result<String>
+    where
+        P: AsRef<Path>,
+    {
+        fs::read_to_string(&filename).map_err(|e| Error::io(filename.as_ref(), e))
     }
 }
 
+/// Keeps the last `capacity` values, pushing more drops the oldest one.
+///
+/// Windows compare and hash only by their values, not by the capacity, so they work as map
+/// keys and a window can be looked up with a collected one.
 #[derive(Debug, Clone)]
-#[allow(dead_code)]
 pub struct LimitedVecDeque<T> {
     deque: VecDeque<T>,
     capacity: usize,
 }
 
-#[allow(dead_code)]
 impl<T> LimitedVecDeque<T> {
     /// Creates a new `LimitedVecDeque` with the given capacity.
     pub fn new(capacity: usize) -> Self {
@@ -43,12 +194,19 @@ impl<T> LimitedVecDeque<T> {
         }
     }
 
-    /// Adds a value to the deque, maintaining the maximum capacity.
-    pub fn push(&mut self, value: T) {
-        if self.deque.len() == self.capacity {
-            self.deque.pop_front(); // Remove the oldest value
+    /// Adds a value to the deque, returning the oldest value if it had to make room.
+    ///
+    /// With a capacity of zero nothing is kept and the value itself comes back.
+    pub fn push(&mut self, value: T) -> Option<T> {
+        if self.capacity == 0 {
+            return Some(value);
         }
+        let evicted = match self.is_full() {
+            true => self.deque.pop_front(),
+            false => None,
+        };
         self.deque.push_back(value);
+        evicted
     }
 
     /// Returns an immutable reference to the underlying `VecDeque`.
@@ -56,64 +214,323 @@ impl<T> LimitedVecDeque<T> {
         &self.deque
     }
 
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
     pub fn len(&self) -> usize {
         self.deque.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.deque.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.deque.len() == self.capacity
+    }
+
+    pub fn clear(&mut self) {
+        self.deque.clear();
+    }
+
+    /// The oldest value.
+    pub fn front(&self) -> Option<&T> {
+        self.deque.front()
+    }
+
+    /// The newest value.
+    pub fn back(&self) -> Option<&T> {
+        self.deque.back()
+    }
+
+    /// The values from the oldest to the newest.
+    pub fn iter(&self) -> std::collections::vec_deque::Iter<'_, T> {
+        self.deque.iter()
+    }
 }
 
-#[allow(dead_code)]
+impl<T: PartialEq> PartialEq for LimitedVecDeque<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.deque == other.deque
+    }
+}
+
+impl<T: Eq> Eq for LimitedVecDeque<T> {}
+
+impl<T: Hash> Hash for LimitedVecDeque<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.deque.hash(state);
+    }
+}
+
+impl<T> Index<usize> for LimitedVecDeque<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.deque[index]
+    }
+}
+
+impl<T> IntoIterator for LimitedVecDeque<T> {
+    type Item = T;
+    type IntoIter = std::collections::vec_deque::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.deque.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LimitedVecDeque<T> {
+    type Item = &'a T;
+    type IntoIter = std::collections::vec_deque::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.deque.iter()
+    }
+}
+
+impl<T> Extend<T> for LimitedVecDeque<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, values: I) {
+        for value in values {
+            self.push(value);
+        }
+    }
+}
+
+/// Collecting keeps all values, the capacity is their number.
+impl<T> FromIterator<T> for LimitedVecDeque<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(values: I) -> Self {
+        let deque: VecDeque<T> = values.into_iter().collect();
+        Self {
+            capacity: deque.len(),
+            deque,
+        }
+    }
+}
+
+/// Items that repeat forever, like a list of moves or the directions a guard turns through.
+#[derive(Debug, Clone)]
 pub struct RingBuffer<T> {
     buffer: Vec<T>,
-    current_index: usize,
+    position: usize,
+    cycle: usize,
 }
 
-#[allow(dead_code)]
-impl<T: Clone> RingBuffer<T> {
-    pub fn new(items: Vec<T>) -> Self {
-        RingBuffer {
-            buffer: items,
-            current_index: 0,
+impl<T> RingBuffer<T> {
+    /// Fails for an empty list, there would be nothing to repeat.
+    pub fn new(items: Vec<T>) -> Result<Self> {
+        if items.is_empty() {
+            return Err(Error::Empty("the ring buffer".to_string()));
         }
+        Ok(RingBuffer {
+            buffer: items,
+            position: 0,
+            cycle: 0,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.buffer.len()
     }
 
+    // Never true, but clippy wants it next to `len`.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    pub fn items(&self) -> &[T] {
+        &self.buffer
+    }
+
+    /// The index of the item `next` returns.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// How often all items were taken, counting from 0. It wraps around after `usize::MAX`
+    /// cycles instead of overflowing.
+    pub fn cycle(&self) -> usize {
+        self.cycle
+    }
+
+    /// The item `next` returns, without taking it.
+    pub fn peek(&self) -> &T {
+        &self.buffer[self.position()]
+    }
+
+    /// Starts over at the first item and the first cycle.
+    pub fn reset(&mut self) {
+        self.position = 0;
+        self.cycle = 0;
+    }
+
+    /// Takes `n` items at once, like `n` calls of `next`.
+    pub fn skip(&mut self, n: usize) {
+        let len = self.buffer.len();
+        let (cycles, rest) = (n / len, n % len);
+        // `position + rest` could overflow for a huge ring of zero-sized items.
+        let (position, carry) = match rest < len - self.position {
+            true => (self.position + rest, 0),
+            false => (rest - (len - self.position), 1),
+        };
+        self.position = position;
+        self.cycle = self.cycle.wrapping_add(cycles).wrapping_add(carry);
+    }
+
+    /// Rotates the items, with a positive `n` the item `n` places ahead comes next.
+    /// The position and the cycle stay the same.
+    pub fn rotate_by(&mut self, n: isize) {
+        let shift = n.rem_euclid(self.buffer.len() as isize) as usize;
+        self.buffer.rotate_left(shift);
+    }
+
+    /// Takes items forever, each one with the cycle it belongs to.
+    pub fn steps(&mut self) -> Steps<'_, T> {
+        Steps { ring: self }
+    }
+}
+
+impl<T: Clone> RingBuffer<T> {
+    // Not an `Iterator` on purpose, a ring buffer never runs out of items.
+    #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> T {
-        let item = self.buffer[self.current_index].clone();
-        self.current_index = (self.current_index + 1) % self.buffer.len();
+        let item = self.peek().clone();
+        self.skip(1);
         item
     }
 }
 
-#[allow(dead_code)]
-pub fn transpose(matrix: Vec<Vec<char>>) -> Vec<Vec<char>> {
-    let rows = matrix.len();
-    let cols = matrix[0].len();
-    let mut transposed_matrix: Vec<Vec<char>> = vec![vec!['.'; rows]; cols];
+/// The endless iterator of `RingBuffer::steps`, yielding `(cycle, item)`.
+pub struct Steps<'a, T> {
+    ring: &'a mut RingBuffer<T>,
+}
 
-    for i in 0..rows {
-        for j in 0..cols {
-            transposed_matrix[j][i] = matrix[i][j];
-        }
+impl<T: Clone> Iterator for Steps<'_, T> {
+    type Item = (usize, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cycle = self.ring.cycle();
+        Some((cycle, self.ring.next()))
     }
 
-    transposed_matrix
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
 }
 
-#[allow(dead_code)]
-pub fn lines_to_matrix(lines: &Vec<String>) -> Vec<Vec<char>> {
-    lines
-        .iter()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .collect::<Vec<Vec<char>>>()
+#[deprecated(note = "use `matrix::find_first`, or `Grid::find_first` on a `Grid`")]
+pub fn find_char(grid: &[Vec<char>], char: char) -> Option<(usize, usize)> {
+    matrix::find_first(grid, &char)
 }
 
-#[allow(dead_code)]
-pub fn find_char(grid: Vec<Vec<char>>, char: char) -> (usize, usize) {
-    for (row_idx, row) in grid.iter().enumerate() {
-        for (col_idx, &c) in row.iter().enumerate() {
-            if c == char {
-                return (row_idx, col_idx);
-            }
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{lines_to_matrix, Direction4, Error, LimitedVecDeque, RingBuffer};
+
+    #[test]
+    #[allow(deprecated)]
+    fn find_char_test() {
+        let grid = lines_to_matrix(&["ab".to_string(), "cd".to_string()]);
+        assert_eq!(super::find_char(&grid, 'c'), Some((1, 0)));
+        assert_eq!(super::find_char(&grid, 'x'), None);
+    }
+
+    #[test]
+    fn limited_vec_deque_test() {
+        let mut window = LimitedVecDeque::new(3);
+        assert!(window.is_empty());
+        assert_eq!(window.push(1), None);
+        assert_eq!(window.push(2), None);
+        assert_eq!(window.push(3), None);
+        assert!(window.is_full());
+        // The oldest values go first.
+        assert_eq!(window.push(4), Some(1));
+        assert_eq!(window.push(5), Some(2));
+        assert_eq!(window.iter().copied().collect::<Vec<_>>(), [3, 4, 5]);
+        assert_eq!(
+            (window.front(), window.back(), window[1]),
+            (Some(&3), Some(&5), 4)
+        );
+
+        window.extend([6, 7]);
+        assert_eq!(window.clone().into_iter().collect::<Vec<_>>(), [5, 6, 7]);
+        window.clear();
+        assert!(window.is_empty() && !window.is_full());
+
+        let collected: LimitedVecDeque<i32> = (1..=4).collect();
+        assert_eq!(collected.capacity(), 4);
+        assert_eq!(collected.len(), 4);
+    }
+
+    #[test]
+    fn limited_vec_deque_zero_capacity_test() {
+        let mut window = LimitedVecDeque::new(0);
+        assert!(window.is_full() && window.is_empty());
+        assert_eq!(window.push('a'), Some('a'));
+        assert_eq!(window.len(), 0);
+        assert_eq!(window.front(), None);
+    }
+
+    #[test]
+    fn limited_vec_deque_key_test() {
+        let mut seen = HashMap::new();
+        let mut window = LimitedVecDeque::new(2);
+        for change in [1, -2, 3, 1, -2] {
+            window.push(change);
+            *seen.entry(window.clone()).or_insert(0) += 1;
         }
+        let key: LimitedVecDeque<i32> = [1, -2].into_iter().collect();
+        assert_eq!(seen[&key], 2);
+
+        // A window that is not full yet matches a collected one with the same values.
+        let mut wide = LimitedVecDeque::new(3);
+        wide.extend([1, 2]);
+        let collected: LimitedVecDeque<i32> = [1, 2].into_iter().collect();
+        assert_ne!(wide.capacity(), collected.capacity());
+        assert_eq!(wide, collected);
+        let seen = HashMap::from([(wide, "wide")]);
+        assert_eq!(seen.get(&collected), Some(&"wide"));
+    }
+
+    #[test]
+    fn ring_buffer_test() {
+        let e = RingBuffer::<char>::new(vec![]).unwrap_err();
+        assert!(matches!(e, Error::Empty(_)));
+
+        let mut moves = RingBuffer::new("LRR".chars().collect()).unwrap();
+        assert_eq!(*moves.peek(), 'L');
+        assert_eq!((moves.next(), moves.next()), ('L', 'R'));
+        assert_eq!(moves.position(), 2);
+        let taken: Vec<_> = moves.steps().take(3).collect();
+        assert_eq!(taken, [(0, 'R'), (1, 'L'), (1, 'R')]);
+
+        // Skipping a huge number of steps does not walk through them.
+        moves.skip(3_000_000_000_001);
+        assert_eq!((moves.cycle(), moves.position()), (1_000_000_000_002, 0));
+        moves.reset();
+        assert_eq!((moves.cycle(), moves.position()), (0, 0));
+
+        moves.rotate_by(-1);
+        assert_eq!(moves.items(), ['R', 'L', 'R']);
+        moves.rotate_by(4);
+        assert_eq!(moves.items(), ['L', 'R', 'R']);
+
+        // Huge skips neither overflow now nor on the next step.
+        moves.skip(usize::MAX);
+        assert_eq!((moves.cycle(), moves.position()), (usize::MAX / 3, 0));
+        assert_eq!(moves.next(), 'L');
+        let mut single = RingBuffer::new(vec![()]).unwrap();
+        single.skip(usize::MAX);
+        single.next();
+        assert_eq!((single.cycle(), single.position()), (0, 0));
+
+        // Turning right four times is a full circle.
+        let mut turns = RingBuffer::new(Direction4::ALL.to_vec()).unwrap();
+        turns.skip(4);
+        assert_eq!(turns.next(), Direction4::N);
+        assert_eq!(turns.next(), Direction4::N.turn_right());
     }
-    panic!("The character '{}' was not found in the grid.", char)
 }
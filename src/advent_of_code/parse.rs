@@ -0,0 +1,278 @@
+use std::any::type_name;
+use std::str::FromStr;
+
+use super::{Error, Grid, Result};
+
+// Small helpers to take puzzle input apart. Every piece of text remembers where
+// it came from, so a malformed line is reported with its line, column and text
+// instead of an `unwrap` panic somewhere in a day.
+
+/// A piece of one input line, `line` and `column` start at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    pub line: usize,
+    pub column: usize,
+    pub text: &'a str,
+    source: &'a str,
+}
+
+impl<'a> Span<'a> {
+    /// The whole line `text` with the given line number.
+    pub fn line(line: usize, text: &'a str) -> Self {
+        Self {
+            line,
+            column: 1,
+            text,
+            source: text,
+        }
+    }
+
+    fn sub(&self, offset: usize, text: &'a str) -> Self {
+        Self {
+            line: self.line,
+            column: self.column + offset,
+            text,
+            source: self.source,
+        }
+    }
+
+    /// A parse error pointing at this span, quoting the whole line.
+    pub fn error(&self, message: impl AsRef<str>) -> Error {
+        Error::parse(
+            self.line,
+            self.column,
+            format!("{} in {:?}", message.as_ref(), self.source),
+        )
+    }
+
+    pub fn trim(&self) -> Self {
+        let start = self.text.len() - self.text.trim_start().len();
+        self.sub(start, self.text.trim())
+    }
+
+    /// Parses the (trimmed) text into any `FromStr` type.
+    pub fn parse<T: FromStr>(&self) -> Result<T> {
+        let trimmed = self.trim();
+        trimmed.text.parse().map_err(|_| {
+            trimmed.error(format!(
+                "cannot parse {:?} as {}",
+                trimmed.text,
+                type_name::<T>()
+            ))
+        })
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Self> {
+        match self.text.strip_prefix(prefix) {
+            Some(rest) => Ok(self.sub(prefix.len(), rest)),
+            None => Err(self.error(format!("expected {prefix:?}"))),
+        }
+    }
+
+    /// Splits at the first `separator`, which has to be there.
+    pub fn split_once(&self, separator: &str) -> Result<(Self, Self)> {
+        match self.text.split_once(separator) {
+            Some((left, right)) => Ok((
+                self.sub(0, left),
+                self.sub(left.len() + separator.len(), right),
+            )),
+            None => Err(self.error(format!("expected {separator:?}"))),
+        }
+    }
+
+    /// Splits at every `separator`.
+    pub fn split(&self, separator: &'a str) -> impl Iterator<Item = Span<'a>> + '_ {
+        let mut offset = 0;
+        self.text.split(separator).map(move |field| {
+            let span = self.sub(offset, field);
+            offset += field.len() + separator.len();
+            span
+        })
+    }
+
+    /// Splits at whitespace, skipping empty pieces.
+    pub fn words(&self) -> impl Iterator<Item = Span<'a>> + '_ {
+        let base = self.text.as_ptr() as usize;
+        self.text
+            .split_whitespace()
+            .map(move |word| self.sub(word.as_ptr() as usize - base, word))
+    }
+
+    /// Splits at every `separator` and parses each field.
+    pub fn fields<T: FromStr>(&self, separator: &'a str) -> Result<Vec<T>> {
+        self.split(separator).map(|field| field.parse()).collect()
+    }
+
+    /// All signed integers in the text, anything in between is skipped.
+    ///
+    /// A `-` only counts as sign if it is directly in front of a digit and not after one,
+    /// so "p=0,4 v=3,-3" gives 0, 4, 3 and -3.
+    pub fn ints<T: FromStr>(&self) -> Result<Vec<T>> {
+        let bytes = self.text.as_bytes();
+        let mut numbers = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            let signed = bytes[i] == b'-'
+                && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+                && (i == 0 || !bytes[i - 1].is_ascii_digit());
+            if !signed && !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            numbers.push(self.sub(start, &self.text[start..i]).parse()?);
+        }
+        Ok(numbers)
+    }
+
+    /// Exactly `N` signed integers, see `ints`.
+    pub fn ints_n<T: FromStr, const N: usize>(&self) -> Result<[T; N]> {
+        let numbers = self.ints()?;
+        let found = numbers.len();
+        numbers
+            .try_into()
+            .map_err(|_| self.error(format!("expected {N} numbers, found {found}")))
+    }
+}
+
+/// All lines of the input with their line number.
+pub fn lines(data: &str) -> impl Iterator<Item = Span<'_>> {
+    data.lines()
+        .enumerate()
+        .map(|(i, line)| Span::line(i + 1, line))
+}
+
+/// Splits the input at blank lines into exactly the `N` named sections.
+pub fn sections<'a, const N: usize>(data: &'a str, names: [&str; N]) -> Result<[Vec<Span<'a>>; N]> {
+    let mut sections: Vec<Vec<Span>> = vec![Vec::new()];
+    for line in lines(data) {
+        match line.text.trim().is_empty() {
+            true => sections.push(Vec::new()),
+            false => sections.last_mut().expect("never empty").push(line),
+        }
+    }
+    // Several blank lines in a row, or at the end, don't start a new section.
+    sections.retain(|section| !section.is_empty());
+
+    if let Some(extra) = sections.get(N) {
+        return Err(extra[0].error(format!("expected only {N} sections")));
+    }
+    if let Some(missing) = names.get(sections.len()) {
+        return Err(Error::MissingSection(missing.to_string()));
+    }
+    Ok(sections.try_into().expect("exactly N sections"))
+}
+
+/// Parses a rectangular grid, `convert` returns `None` for characters that are not allowed.
+pub fn grid<T>(data: &str, convert: impl Fn(char) -> Option<T>) -> Result<Grid<T>> {
+    let mut cells = Vec::new();
+    let mut width = None;
+    let mut height = 0;
+    for line in lines(data) {
+        for (column, char) in line.text.chars().enumerate() {
+            let cell = convert(char).ok_or(Error::InvalidChar {
+                char,
+                line: line.line,
+                column: column + 1,
+            })?;
+            cells.push(cell);
+        }
+        let line_width = line.text.chars().count();
+        match width {
+            None => width = Some(line_width),
+            Some(width) if width != line_width => {
+                return Err(line.error(format!(
+                    "line has {line_width} cells, the ones before have {width}"
+                )));
+            }
+            Some(_) => {}
+        }
+        height += 1;
+    }
+    Ok(Grid::new(width.unwrap_or(0), height, cells))
+}
+
+/// Parses a rectangular grid of any characters.
+pub fn char_grid(data: &str) -> Result<Grid<char>> {
+    grid(data, Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{char_grid, grid, lines, sections, Span};
+    use crate::advent_of_code::Error;
+
+    #[test]
+    fn ints_test() {
+        let line = Span::line(1, "p=0,4 v=3,-3");
+        assert_eq!(line.ints::<isize>().unwrap(), vec![0, 4, 3, -3]);
+        assert_eq!(line.ints_n::<isize, 4>().unwrap(), [0, 4, 3, -3]);
+        assert!(line.ints_n::<isize, 2>().is_err());
+        assert_eq!(
+            Span::line(1, "1-2 x-5").ints::<i32>().unwrap(),
+            vec![1, 2, -5]
+        );
+        let e = Span::line(4, "x=300").ints::<u8>().unwrap_err();
+        assert!(matches!(
+            e,
+            Error::Parse {
+                line: 4,
+                column: 3,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn fields_test() {
+        let line = Span::line(2, "190: 10 19");
+        let (key, values) = line.split_once(":").unwrap();
+        assert_eq!(key.parse::<usize>().unwrap(), 190);
+        let words: Vec<_> = values.words().collect();
+        assert_eq!((words[1].text, words[1].column), ("19", 9));
+        assert_eq!(
+            Span::line(1, "75,47,61").fields::<usize>(",").unwrap(),
+            vec![75, 47, 61]
+        );
+        let e = Span::line(3, "75,4x,61").fields::<usize>(",").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 3, column 4: cannot parse \"4x\" as usize in \"75,4x,61\""
+        );
+        assert!(Span::line(1, "190 10").split_once(":").is_err());
+    }
+
+    #[test]
+    fn sections_test() {
+        let [rules, updates] = sections("1|2\n2|3\n\n1,2,3\n", ["rules", "updates"]).unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!((updates[0].line, updates[0].text), (4, "1,2,3"));
+        let e = sections("1|2\n", ["rules", "updates"]).unwrap_err();
+        assert!(matches!(e, Error::MissingSection(name) if name == "updates"));
+        assert!(sections("a\n\nb\n\nc", ["a", "b"]).is_err());
+        assert_eq!(lines("a\nb").count(), 2);
+    }
+
+    #[test]
+    fn grid_test() {
+        let g = char_grid("ab\ncd").unwrap();
+        assert_eq!(g[(1, 0)], 'c');
+        assert!(matches!(
+            char_grid("ab\nc").unwrap_err(),
+            Error::Parse { line: 2, .. }
+        ));
+        let e = grid("12\n3x", |c| c.to_digit(10)).unwrap_err();
+        assert!(matches!(
+            e,
+            Error::InvalidChar {
+                char: 'x',
+                line: 2,
+                column: 2
+            }
+        ));
+    }
+}
@@ -0,0 +1,286 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use super::answers::ANSWERS_FILE;
+use super::{Error, Reader, Result};
+
+// `aoc new <day>`: everything a new day needs, relative to the repository root.
+
+const REGISTRY: &str = "src/days/mod.rs";
+
+// The answers, also the ones of the example, go into the answers file, so there is no test
+// module to start with.
+fn module_template(day: u8) -> String {
+    format!(
+        r#"use crate::advent_of_code::{{Answer, Result, Solution}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    type Input = Vec<String>;
+
+    fn parse(data: &str) -> Result<Self::Input> {{
+        Ok(data.lines().map(String::from).collect())
+    }}
+
+    fn part1(_input: &Self::Input) -> Answer {{
+        Answer::Unsolved
+    }}
+}}
+"#
+    )
+}
+
+fn puzzle_entry(day: u8) -> String {
+    format!(
+        r#"    Puzzle {{
+        day: {day},
+        input: "./input/day{day}.txt",
+        examples: ["./input/day{day}_test.txt", "./input/day{day}_test.txt"],
+        solve: Day{day}::solve,
+        solve_example: Day{day}::solve_example,
+        time_phases: Day{day}::time_phases,
+        time_example_phases: Day{day}::time_example_phases,
+    }},"#
+    )
+}
+
+// The answers are commented out until they are known, so the regression test ignores them.
+fn answers_stub(day: u8) -> String {
+    format!("# {day} 1 input/day{day}_test.txt <answer>\n# {day} 1 input/day{day}.txt <answer>\n")
+}
+
+// Adds `line` to the block of lines starting with `prefix`, keeping it sorted like rustfmt does.
+fn insert_sorted(lines: &mut Vec<String>, prefix: &str, line: String) -> Result<()> {
+    let block: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with(prefix))
+        .collect();
+    let last = *block
+        .last()
+        .ok_or_else(|| Error::MissingSection(format!("'{prefix}' lines in {REGISTRY}")))?;
+    // rustfmt compares the names, so "day1" comes before "day15" but "day1;" would not.
+    let name = |line: &str| {
+        let rest = &line[prefix.len()..];
+        rest[..rest
+            .find(|c: char| !c.is_alphanumeric())
+            .unwrap_or(rest.len())]
+            .to_string()
+    };
+    let at = block
+        .into_iter()
+        .find(|&i| name(&lines[i]) > name(&line))
+        .unwrap_or(last + 1);
+    lines.insert(at, line);
+    Ok(())
+}
+
+/// Adds the module, its import and the `PUZZLES` entry of `day` to the registry source.
+pub fn register(registry: &str, day: u8) -> Result<String> {
+    let module = format!("pub mod day{day};");
+    if registry.lines().any(|line| line == module) {
+        return Err(Error::AlreadyExists(format!("day {day} in {REGISTRY}")));
+    }
+
+    let mut lines: Vec<String> = registry.lines().map(String::from).collect();
+    insert_sorted(&mut lines, "pub mod day", module)?;
+    insert_sorted(
+        &mut lines,
+        "use self::day",
+        format!("use self::day{day}::Day{day};"),
+    )?;
+
+    // The entries are ordered by day, each one starts the line before its `day: N,`.
+    let end = lines
+        .iter()
+        .position(|line| line == "];")
+        .ok_or_else(|| Error::MissingSection(format!("PUZZLES in {REGISTRY}")))?;
+    let at = (1..end)
+        .find(|&i| {
+            lines[i]
+                .trim()
+                .strip_prefix("day: ")
+                .and_then(|n| n.trim_end_matches(',').parse::<u8>().ok())
+                .is_some_and(|n| n > day)
+        })
+        .map_or(end, |i| i - 1);
+    lines.insert(at, puzzle_entry(day));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+// Writes a new file, failing if it is already there.
+fn create(path: &Path, content: &str) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .map_err(|e| match e.kind() {
+            io::ErrorKind::AlreadyExists => Error::AlreadyExists(path.display().to_string()),
+            _ => Error::io(path, e),
+        })?;
+    file.write_all(content.as_bytes())
+        .map_err(|e| Error::io(path, e))
+}
+
+/// Creates the module, the input files and the answers stub for `day` and registers it.
+///
+/// Nothing is written if any of the files already exists. Returns the created and changed files.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>> {
+    let module = root.join(format!("src/days/day{day}.rs"));
+    let inputs = [
+        root.join(format!("input/day{day}.txt")),
+        root.join(format!("input/day{day}_test.txt")),
+    ];
+    if let Some(existing) = inputs.iter().chain([&module]).find(|p| p.exists()) {
+        return Err(Error::AlreadyExists(existing.display().to_string()));
+    }
+
+    let registry_path = root.join(REGISTRY);
+    let registry = register(&Reader::read_to_string(&registry_path)?, day)?;
+    let answers_path = root.join(ANSWERS_FILE.trim_start_matches("./"));
+    let mut answers = match Reader::read_to_string(&answers_path) {
+        Ok(answers) => answers,
+        Err(Error::Io { source, .. }) if source.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+    if !answers.is_empty() && !answers.ends_with('\n') {
+        answers.push('\n');
+    }
+    answers += &answers_stub(day);
+
+    create(&module, &module_template(day))?;
+    for input in &inputs {
+        create(input, "")?;
+    }
+    fs::write(&registry_path, registry).map_err(|e| Error::io(&registry_path, e))?;
+    fs::write(&answers_path, answers).map_err(|e| Error::io(&answers_path, e))?;
+
+    Ok([module]
+        .into_iter()
+        .chain(inputs)
+        .chain([registry_path, answers_path])
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{new_day, register};
+    use crate::advent_of_code::temp_dir::TempDir;
+    use crate::advent_of_code::Error;
+
+    // A registry like src/days/mod.rs with a few days, so the tests do not depend on the
+    // days that are really there.
+    const FIXTURE: &str = r#"pub mod day1;
+pub mod day14;
+pub mod day17;
+pub mod day7;
+
+use self::day1::Day1;
+use self::day14::Day14;
+use self::day17::Day17;
+use self::day7::Day7;
+use crate::advent_of_code::{Answer, PhaseTimes, Solution};
+
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle {
+        day: 1,
+        input: "./input/day1.txt",
+        examples: ["./input/day1_1_test.txt", "./input/day1_1_test.txt"],
+        solve: Day1::solve,
+        solve_example: Day1::solve_example,
+        time_phases: Day1::time_phases,
+        time_example_phases: Day1::time_example_phases,
+    },
+    Puzzle {
+        day: 7,
+        input: "./input/day7.txt",
+        examples: ["./input/day7_test.txt", "./input/day7_test.txt"],
+        solve: Day7::solve,
+        solve_example: Day7::solve_example,
+        time_phases: Day7::time_phases,
+        time_example_phases: Day7::time_example_phases,
+    },
+    Puzzle {
+        day: 14,
+        input: "./input/day14.txt",
+        examples: ["./input/day14_test.txt", "./input/day14_test.txt"],
+        solve: Day14::solve,
+        solve_example: Day14::solve_example,
+        time_phases: Day14::time_phases,
+        time_example_phases: Day14::time_example_phases,
+    },
+    Puzzle {
+        day: 17,
+        input: "./input/day17.txt",
+        examples: ["./input/day17_test.txt", "./input/day17_test.txt"],
+        solve: Day17::solve,
+        solve_example: Day17::solve_example,
+        time_phases: Day17::time_phases,
+        time_example_phases: Day17::time_example_phases,
+    },
+];
+"#;
+
+    #[test]
+    fn register_test() {
+        let updated = register(FIXTURE, 15).unwrap();
+        let lines: Vec<&str> = updated.lines().collect();
+        let position = |line: &str| lines.iter().position(|l| *l == line).unwrap();
+        assert_eq!(position("pub mod day15;"), position("pub mod day14;") + 1);
+        assert_eq!(
+            position("use self::day15::Day15;"),
+            position("use self::day14::Day14;") + 1
+        );
+        assert!(position("        day: 15,") > position("        day: 14,"));
+        assert!(position("        day: 15,") < position("        day: 17,"));
+        assert!(updated.contains("        time_phases: Day15::time_phases,\n"));
+
+        let e = register(FIXTURE, 7).unwrap_err();
+        assert!(matches!(e, Error::AlreadyExists(_)));
+        let updated = register(FIXTURE, 25).unwrap();
+        // The last day goes at the end of the list.
+        assert!(updated
+            .contains("        time_example_phases: Day25::time_example_phases,\n    },\n];\n"));
+        let e = register("pub mod day1;\n", 2).unwrap_err();
+        assert!(matches!(e, Error::MissingSection(_)));
+    }
+
+    #[test]
+    fn new_day_test() {
+        let dir = TempDir::new("new_day_test");
+        let root = dir.path();
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::create_dir_all(root.join("input")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), FIXTURE).unwrap();
+        fs::write(root.join("input/answers.txt"), "7 1 input/day7.txt 1").unwrap();
+
+        let created = new_day(root, 15).unwrap();
+        assert_eq!(created.len(), 5);
+        let module = fs::read_to_string(root.join("src/days/day15.rs")).unwrap();
+        assert!(module.contains("impl Solution for Day15 {"));
+        assert!(!module.contains("#[test]"));
+        assert!(fs::read_to_string(root.join("src/days/mod.rs"))
+            .unwrap()
+            .contains("pub mod day15;"));
+        assert_eq!(
+            fs::read_to_string(root.join("input/day15.txt")).unwrap(),
+            ""
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("input/answers.txt")).unwrap(),
+            "7 1 input/day7.txt 1\n# 15 1 input/day15_test.txt <answer>\n# 15 1 input/day15.txt <answer>\n"
+        );
+
+        // A second run must not touch anything.
+        fs::write(root.join("src/days/day15.rs"), "keep me").unwrap();
+        let e = new_day(root, 15).unwrap_err();
+        assert!(matches!(e, Error::AlreadyExists(_)));
+        assert_eq!(
+            fs::read_to_string(root.join("src/days/day15.rs")).unwrap(),
+            "keep me"
+        );
+    }
+}
@@ -0,0 +1,334 @@
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::client::{Client, YEAR};
+use super::parse;
+use super::{Error, Reader, Result};
+
+/// Every submitted answer and what the site said about it, one `day part answer outcome` per line.
+pub const HISTORY_FILE: &str = "./input/submissions.txt";
+
+/// What the site says about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// Submitted too soon after the last one, the wait time if the page tells it.
+    RateLimited(Option<Duration>),
+    /// The part is solved already, or not unlocked yet.
+    WrongLevel,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too_high"),
+            Outcome::TooLow => write!(f, "too_low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited(_) => write!(f, "rate_limited"),
+            Outcome::WrongLevel => write!(f, "wrong_level"),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "too_high" => Ok(Outcome::TooHigh),
+            "too_low" => Ok(Outcome::TooLow),
+            "wrong" => Ok(Outcome::Wrong),
+            "rate_limited" => Ok(Outcome::RateLimited(None)),
+            "wrong_level" => Ok(Outcome::WrongLevel),
+            _ => Err(format!("unknown outcome '{s}'")),
+        }
+    }
+}
+
+// "You have 1m 20s left to wait." gives 80 seconds.
+fn parse_wait(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+    page[start..end]
+        .split_whitespace()
+        .map(|part| {
+            let (number, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let number: u64 = number.parse().ok()?;
+            match unit {
+                "s" => Some(number),
+                "m" => Some(number * 60),
+                "h" => Some(number * 3600),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Reads the outcome from the page the site answers a submission with.
+pub fn parse_outcome(page: &str) -> Option<Outcome> {
+    if page.contains("That's the right answer") {
+        Some(Outcome::Correct)
+    } else if page.contains("your answer is too high") {
+        Some(Outcome::TooHigh)
+    } else if page.contains("your answer is too low") {
+        Some(Outcome::TooLow)
+    } else if page.contains("That's not the right answer") {
+        Some(Outcome::Wrong)
+    } else if page.contains("You gave an answer too recently") {
+        Some(Outcome::RateLimited(parse_wait(page)))
+    } else if page.contains("You don't seem to be solving the right level") {
+        Some(Outcome::WrongLevel)
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// All earlier attempts, used to not send answers that are known to be wrong.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History {
+    pub attempts: Vec<Attempt>,
+}
+
+impl History {
+    pub fn parse(data: &str) -> Result<Self> {
+        let attempts = parse::lines(data)
+            .filter(|line| !line.text.trim().is_empty() && !line.text.starts_with('#'))
+            .map(|line| {
+                let words: Vec<_> = line.words().collect();
+                let [day, part, answer, outcome] = words[..] else {
+                    return Err(line.error("expected day, part, answer and outcome"));
+                };
+                Ok(Attempt {
+                    day: day.parse()?,
+                    part: part.parse()?,
+                    answer: answer.text.to_string(),
+                    outcome: outcome.parse()?,
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { attempts })
+    }
+
+    /// Reads the history, there is none yet if the file is missing.
+    pub fn load(path: &Path) -> Result<Self> {
+        match Reader::read_to_string(path) {
+            Ok(data) => Self::parse(&data).map_err(|e| e.in_file(path)),
+            Err(Error::Io { source, .. }) if source.kind() == io::ErrorKind::NotFound => {
+                Ok(Self::default())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Adds the attempt to the history file.
+    pub fn record(path: &Path, attempt: &Attempt) -> Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| Error::io(path, e))?;
+        writeln!(
+            file,
+            "{} {} {} {}",
+            attempt.day, attempt.part, attempt.answer, attempt.outcome
+        )
+        .map_err(|e| Error::io(path, e))
+    }
+
+    /// Why `answer` should not be sent, if the earlier attempts already rule it out.
+    pub fn refusal(&self, day: u8, part: u8, answer: &str) -> Option<String> {
+        let attempts: Vec<&Attempt> = self
+            .attempts
+            .iter()
+            .filter(|a| a.day == day && a.part == part)
+            .collect();
+        if let Some(correct) = attempts.iter().find(|a| a.outcome == Outcome::Correct) {
+            return Some(format!(
+                "part {part} is solved already with {}",
+                correct.answer
+            ));
+        }
+        if let Some(tried) = attempts.iter().find(|a| {
+            a.answer == answer
+                && matches!(
+                    a.outcome,
+                    Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong
+                )
+        }) {
+            return Some(format!(
+                "{answer} was tried before, it is {}",
+                tried.outcome
+            ));
+        }
+
+        // Only numbers have bounds.
+        let number: i128 = answer.parse().ok()?;
+        let bound = |outcome| {
+            attempts
+                .iter()
+                .filter(move |a| a.outcome == outcome)
+                .filter_map(|a| a.answer.parse::<i128>().ok())
+        };
+        if let Some(high) = bound(Outcome::TooHigh).min().filter(|&high| number >= high) {
+            return Some(format!("{answer} is not below {high}, which is too high"));
+        }
+        if let Some(low) = bound(Outcome::TooLow).max().filter(|&low| number <= low) {
+            return Some(format!("{answer} is not above {low}, which is too low"));
+        }
+        None
+    }
+}
+
+// Everything but letters, digits and `-_.` is percent-encoded, answers can be like "7,6,1".
+fn url_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'.' => (b as char).to_string(),
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+/// Sends `answer` for a part, unless the history rules it out, and records the attempt.
+pub fn submit(
+    client: &Client,
+    history_path: &Path,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Outcome> {
+    if let Some(reason) = History::load(history_path)?.refusal(day, part, answer) {
+        return Err(Error::Refused(reason));
+    }
+
+    let path = format!("/{YEAR}/day/{day}/answer");
+    let form = format!("level={part}&answer={}", url_encode(answer));
+    let response = client.post(&path, &form)?;
+    let outcome = match response.status {
+        200 => parse_outcome(&response.body),
+        _ => None,
+    }
+    .ok_or_else(|| Error::Http {
+        url: client.url(&path),
+        message: format!("unexpected response with status {}", response.status),
+    })?;
+
+    History::record(
+        history_path,
+        &Attempt {
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+        },
+    )?;
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::time::Duration;
+
+    use super::{parse_outcome, submit, url_encode, History, Outcome};
+    use crate::advent_of_code::client::stand_in::{curl_missing, StandIn};
+    use crate::advent_of_code::client::Client;
+    use crate::advent_of_code::temp_dir::TempDir;
+    use crate::advent_of_code::Error;
+
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.</p></article></main>";
+    const CORRECT: &str = "<main><article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to finding the Chief Historian.</p></article></main>";
+
+    #[test]
+    fn parse_outcome_test() {
+        assert_eq!(parse_outcome(TOO_HIGH), Some(Outcome::TooHigh));
+        assert_eq!(parse_outcome(CORRECT), Some(Outcome::Correct));
+        assert_eq!(
+            parse_outcome("That's not the right answer; your answer is too low."),
+            Some(Outcome::TooLow)
+        );
+        assert_eq!(
+            parse_outcome("<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.</p>"),
+            Some(Outcome::RateLimited(Some(Duration::from_secs(65))))
+        );
+        assert_eq!(
+            parse_outcome(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Some(Outcome::WrongLevel)
+        );
+        assert_eq!(parse_outcome("<html>Log in</html>"), None);
+    }
+
+    #[test]
+    fn refusal_test() {
+        let history =
+            History::parse("7 1 100 too_high\n7 1 50 too_low\n7 1 70 wrong\n7 2 1 correct\n")
+                .unwrap();
+        assert!(history.refusal(7, 1, "70").is_some());
+        assert!(history.refusal(7, 1, "100").is_some());
+        assert!(history.refusal(7, 1, "150").is_some());
+        assert!(history.refusal(7, 1, "50").is_some());
+        assert!(history.refusal(7, 1, "20").is_some());
+        assert_eq!(history.refusal(7, 1, "75"), None);
+        assert!(history.refusal(7, 2, "2").is_some());
+        assert_eq!(history.refusal(8, 1, "100"), None);
+        assert!(History::parse("7 1 100").is_err());
+        assert_eq!(url_encode("7,6,1"), "7%2C6%2C1");
+    }
+
+    #[test]
+    fn submit_test() {
+        if curl_missing() {
+            return;
+        }
+        let dir = TempDir::new("submissions_test");
+        let path = dir.join("submissions.txt");
+        let server = StandIn::start(200, TOO_HIGH);
+        let client = Client::new(&server.url, "secret");
+
+        assert_eq!(
+            submit(&client, &path, 7, 1, "100").unwrap(),
+            Outcome::TooHigh
+        );
+        let requests = server.requests();
+        assert!(requests[0].starts_with("POST /2024/day/7/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=1&answer=100"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "7 1 100 too_high\n");
+
+        // Known to be too high, nothing is sent.
+        let e = submit(&client, &path, 7, 1, "120").unwrap_err();
+        assert!(matches!(e, Error::Refused(_)));
+        assert_eq!(server.requests().len(), 1);
+
+        let server = StandIn::start(200, CORRECT);
+        let client = Client::new(&server.url, "secret");
+        assert_eq!(
+            submit(&client, &path, 7, 1, "90").unwrap(),
+            Outcome::Correct
+        );
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "7 1 100 too_high\n7 1 90 correct\n"
+        );
+    }
+}
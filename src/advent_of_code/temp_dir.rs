@@ -0,0 +1,54 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::{env, fs, process};
+
+// Tests run in parallel, so every directory gets its own number on top of the name.
+static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+/// An empty directory for the files of one test, removed again when it is dropped, also
+/// when the test fails.
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    pub fn new(name: &str) -> Self {
+        let n = NEXT.fetch_add(1, Ordering::Relaxed);
+        let path = env::temp_dir().join(format!("aoc_{name}_{}_{n}", process::id()));
+        fs::create_dir_all(&path).unwrap();
+        Self { path }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn join(&self, path: impl AsRef<Path>) -> PathBuf {
+        self.path.join(path)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::TempDir;
+
+    #[test]
+    fn temp_dir_test() {
+        let dir = TempDir::new("temp_dir_test");
+        let other = TempDir::new("temp_dir_test");
+        assert_ne!(dir.path(), other.path());
+        fs::write(dir.join("file.txt"), "content").unwrap();
+        let path = dir.path().to_path_buf();
+        drop(dir);
+        assert!(!path.exists());
+        assert!(other.path().is_dir());
+    }
+}
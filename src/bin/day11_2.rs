@@ -0,0 +1,8 @@
+use aoc_2024::advent_of_code::Solution;
+use aoc_2024::days::day11::Day11;
+
+fn main() {
+    let d = "125 17";
+    let result = Day11::part2(&Day11::parse(d).unwrap());
+    println!("result: {result}");
+}
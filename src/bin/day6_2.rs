@@ -0,0 +1,10 @@
+use std::fs;
+
+use aoc_2024::advent_of_code::Solution;
+use aoc_2024::days::day6::Day6;
+
+fn main() {
+    let d = fs::read_to_string("./input/day6_1_test.txt").unwrap();
+    let result = Day6::part2(&Day6::parse(&d).unwrap());
+    println!("result: {result}");
+}
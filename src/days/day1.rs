@@ -0,0 +1,92 @@
+use std::cmp::max;
+use std::cmp::min;
+use std::collections::HashMap;
+
+use crate::advent_of_code::parse;
+use crate::advent_of_code::{Answer, Result, Solution};
+
+// There is no build in `abs` for u32 in Rust, who would have guessed that.
+fn absolute_difference_u32(a: &u32, b: &u32) -> u32 {
+    max(a, b) - min(a, b)
+}
+
+// The puzlle calls for two lists (given as two columns in a ascii file).
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = (Vec<u32>, Vec<u32>);
+
+    // split each input at the whitespace and push the values into the lists.
+    fn parse(data: &str) -> Result<Self::Input> {
+        let pairs = parse::lines(data)
+            .map(|line| {
+                let [left, right] = line.ints_n::<u32, 2>()?;
+                Ok((left, right))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(pairs.into_iter().unzip())
+    }
+
+    // The lists need to be sorted and line by line the absolute differences need to be summed up.
+    fn part1((left_list, right_list): &Self::Input) -> Answer {
+        let mut left_list = left_list.clone();
+        let mut right_list = right_list.clone();
+        // sort the lists.
+        left_list.sort();
+        right_list.sort();
+        // iterate over both lists, map the absolute difference funtion onto each element and buld the sum
+        left_list
+            .iter()
+            .zip(right_list.iter())
+            .map(|(x, y)| absolute_difference_u32(x, y))
+            .sum::<u32>()
+            .into()
+    }
+
+    // This time, we need to determine who often a number from the left list apears in the right list.
+    // Thus, the right list must be a Hashmap with the number as key and the value how often it was in the right list.
+    fn part2((left_list, right_list): &Self::Input) -> Answer {
+        let mut right_hm: HashMap<u32, u32> = HashMap::new();
+        right_list.iter().for_each(|&num| {
+            right_hm
+                .entry(num)
+                .and_modify(|count| *count += 1)
+                .or_insert(1);
+        });
+        // iterate over the left list and lookup how often the respective number was in the left list, multiply itseld with the number of occurrences, and bum the products up.
+        left_list
+            .iter()
+            .map(|key| {
+                match right_hm.get(key) {
+                    Some(count) => count * key,
+                    None => 0, // if the number is not in the right list, it's not in the count
+                }
+            })
+            .sum::<u32>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::Day1;
+    use crate::advent_of_code::Solution;
+
+    #[test]
+    fn res_test() {
+        let d = fs::read_to_string("./input/day1_1_test.txt").unwrap();
+        let result = Day1::part1(&Day1::parse(&d).unwrap());
+        println!("result: {result}");
+        assert_eq!(result, 11.into());
+    }
+
+    #[test]
+    fn res_test_part2() {
+        let d = fs::read_to_string("./input/day1_1_test.txt").unwrap();
+        let result = Day1::part2(&Day1::parse(&d).unwrap());
+        println!("result: {result}");
+        assert_eq!(result, 31.into());
+    }
+}
@@ -0,0 +1,50 @@
+use crate::advent_of_code::{graph, parse, Answer, Grid, Result, Solution};
+
+// Every step of a trail goes up by exactly one.
+fn uphill(
+    grid: &Grid<usize>,
+    position: (usize, usize),
+) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let height = grid[position];
+    grid.neighbours4(position)
+        .filter(move |&next| grid[next] == height + 1)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<usize>;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse::grid(data, |c| c.to_digit(10).map(|height| height as usize))
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        grid.find_all(&0)
+            .map(|trailhead| {
+                graph::bfs([trailhead], |&position| uphill(grid, position))
+                    .distances()
+                    .keys()
+                    .filter(|&&position| grid[position] == 9)
+                    .count()
+            })
+            .sum::<usize>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::Day10;
+    use crate::advent_of_code::Solution;
+
+    #[test]
+    fn res_test() {
+        let d = fs::read_to_string("./input/day10_test.txt").unwrap();
+        let result = Day10::part1(&Day10::parse(&d).unwrap());
+        println!("result: {result}");
+        assert_eq!(result, 36.into());
+    }
+}
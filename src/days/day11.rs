@@ -0,0 +1,166 @@
+use crate::advent_of_code::{parse, Answer, Bag, Digits, Result, Solution};
+
+// The engraved numbers are u64, but times 2024 they don't fit into one any more. A number
+// with an odd number of digits grows by at most 4 digits and then has an even number of
+// them once more, so from 20 digits on it only gets split: u128 is always big enough.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stone {
+    value: u128,
+}
+
+impl Stone {
+    fn new(value: u128) -> Self {
+        Self { value }
+    }
+
+    fn blink(&mut self) -> Vec<Self> {
+        // first rule: if value==0, return Stone with value = 1
+        if self.value == 0 {
+            vec![Stone::new(1)]
+        // second rule: if the number has and even number of digits, split into two Stones
+        } else if self.value.digit_count().is_multiple_of(2) {
+            let (left_half, right_half) = self
+                .value
+                .split_digits_at(self.value.digit_count() / 2)
+                .unwrap();
+            vec![Stone::new(left_half), Stone::new(right_half)]
+        } else {
+            vec![Stone::new(self.value * 2024)]
+        }
+    }
+}
+
+/// The stones after some number of blinks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Generation {
+    /// The number of different values.
+    pub distinct: usize,
+    /// The number of stones, `None` once it does not fit into a u64 any more.
+    pub total: Option<u64>,
+}
+
+// The order of the stones never matters, so equal ones are only counted. The first
+// generation is the one before blinking.
+pub fn evolve(stones: &[Stone], n_blinks: usize) -> Vec<Generation> {
+    let mut values: Bag<u128> = stones.iter().map(|s| s.value).collect();
+    let mut overflowed = false;
+    let mut generations = Vec::with_capacity(n_blinks + 1);
+    for blink in 0..=n_blinks {
+        generations.push(Generation {
+            distinct: values.distinct(),
+            total: values.checked_total().filter(|_| !overflowed),
+        });
+        if blink == n_blinks {
+            break;
+        }
+        let next = |&value: &u128| Stone::new(value).blink().into_iter().map(|s| s.value);
+        values = values.checked_flat_map(next).unwrap_or_else(|| {
+            // Too many to count, but the values themselves still evolve the same way.
+            overflowed = true;
+            values.iter().flat_map(|(value, _)| next(value)).collect()
+        });
+    }
+    generations
+}
+
+fn count_after_blinks(stones: &[Stone], n_blinks: usize) -> u64 {
+    evolve(stones, n_blinks)[n_blinks]
+        .total
+        .expect("Too many stones to count.")
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Stone>;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse::lines(data)
+            .flat_map(|line| line.words().collect::<Vec<_>>())
+            .map(|word| word.parse::<u64>().map(|value| Stone::new(value.into())))
+            .collect()
+    }
+
+    fn part1(stones: &Self::Input) -> Answer {
+        count_after_blinks(stones, 25).into()
+    }
+
+    fn part2(stones: &Self::Input) -> Answer {
+        count_after_blinks(stones, 75).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_after_blinks, evolve, Day11, Stone};
+    use crate::advent_of_code::Solution;
+
+    #[test]
+    fn rule1_test() {
+        let mut stone = Stone::new(0);
+        let blinked = stone.blink();
+        assert_eq!(blinked.len(), 1);
+        assert_eq!(blinked[0].value, 1);
+    }
+
+    #[test]
+    fn rule2_test() {
+        let mut stone = Stone::new(253000);
+        let blinked = stone.blink();
+        assert_eq!(blinked.len(), 2);
+        assert_eq!(blinked[0].value, 253);
+        assert_eq!(blinked[1].value, 0);
+    }
+
+    #[test]
+    fn rule3_test() {
+        let mut stone = Stone::new(1);
+        let blinked = stone.blink();
+        assert_eq!(blinked.len(), 1);
+        assert_eq!(blinked[0].value, 2024);
+    }
+
+    #[test]
+    fn big_stone_test() {
+        // Times 2024 this is too big for a u64.
+        let mut stone = Stone::new(1_000_000_000_000_000_000);
+        assert_eq!(stone.blink()[0].value, 2_024_000_000_000_000_000_000);
+        let after_first_blink = count_after_blinks(&stone.blink(), 24);
+        assert_eq!(
+            Day11::solve("1000000000000000000", 1).unwrap(),
+            after_first_blink.into()
+        );
+    }
+
+    #[test]
+    fn blinks_test() {
+        let result = count_after_blinks(&Day11::parse("125 17").unwrap(), 6);
+        println!("result: {result}");
+        assert_eq!(result, 22);
+    }
+
+    #[test]
+    fn res_test() {
+        let result = Day11::part1(&Day11::parse("125 17").unwrap());
+        println!("result: {result}");
+        assert_eq!(result, 55312.into());
+    }
+
+    #[test]
+    fn res_test_part2() {
+        let result = Day11::part2(&Day11::parse("125 17").unwrap());
+        println!("result: {result}");
+        assert_eq!(result, 65601038650482u64.into());
+    }
+
+    #[test]
+    fn many_blinks_test() {
+        let generations = evolve(&Day11::parse("125 17").unwrap(), 1000);
+        assert_eq!(generations.len(), 1001);
+        assert_eq!(generations[25].total, Some(55312));
+        // Far too many stones, but only a few thousand different values.
+        assert_eq!(generations[1000].total, None);
+        println!("distinct values: {}", generations[1000].distinct);
+        assert_eq!(generations[1000].distinct, generations[999].distinct);
+    }
+}
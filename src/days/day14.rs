@@ -0,0 +1,153 @@
+use crate::advent_of_code::parse::{self, Span};
+use crate::advent_of_code::{math, Answer, Point, Result, Solution, Vec2};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Robot {
+    position: Point,
+    velocity: Vec2,
+    grid_size: (isize, isize),
+}
+
+impl Robot {
+    fn new(position: Point, velocity: Vec2, grid_size: (isize, isize)) -> Self {
+        Self {
+            position,
+            velocity,
+            grid_size,
+        }
+    }
+
+    fn from_line(line: &Span, grid_size: (isize, isize)) -> Result<Self> {
+        // p=0,4 v=3,-3
+        let (p, v) = line.split_once(" ")?;
+        let [pos_x, pos_y] = p.strip_prefix("p=")?.ints_n()?;
+        let [vel_x, vel_y] = v.strip_prefix("v=")?.ints_n()?;
+        Ok(Self::new(
+            Point::new(pos_x, pos_y),
+            Vec2::new(vel_x, vel_y),
+            grid_size,
+        ))
+    }
+
+    fn move_robot(&mut self, time: isize) {
+        // Move the robot
+        self.position += self.velocity * time;
+
+        // Wrap around, `%` would leave negative positions
+        self.position.x = math::modulo(self.position.x, self.grid_size.0);
+        self.position.y = math::modulo(self.position.y, self.grid_size.1);
+    }
+}
+
+fn count_robots_in_quadrants(robots: Vec<Robot>) -> [isize; 4] {
+    let center_x = robots[0].grid_size.0 / 2; // Vertical center line
+    let center_y = robots[0].grid_size.1 / 2; // Horizontal center line
+
+    let mut quadrants = [0; 4]; // Q1, Q2, Q3, Q4 counts
+
+    for robot in robots {
+        let Point { x, y } = robot.position;
+
+        // Ignore robots on the center lines
+        if x == center_x || y == center_y {
+            continue;
+        }
+
+        // Classify the robot into a quadrant
+        if x > center_x && y < center_y {
+            quadrants[0] += 1; // Q1: Top-right
+        } else if x < center_x && y < center_y {
+            quadrants[1] += 1; // Q2: Top-left
+        } else if x < center_x && y > center_y {
+            quadrants[2] += 1; // Q3: Bottom-left
+        } else if x > center_x && y > center_y {
+            quadrants[3] += 1; // Q4: Bottom-right
+        }
+    }
+
+    quadrants
+}
+
+// The puzzle is played on a 101x103 grid, the example on a 11x7 one. The size is not
+// part of the input.
+const GRID_SIZE: (isize, isize) = (101, 103);
+const EXAMPLE_GRID_SIZE: (isize, isize) = (11, 7);
+
+fn parse_robots(data: &str, grid_size: (isize, isize)) -> Result<Vec<Robot>> {
+    parse::lines(data)
+        .map(|line| Robot::from_line(&line, grid_size))
+        .collect()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse_robots(data, GRID_SIZE)
+    }
+
+    fn parse_example(data: &str) -> Result<Self::Input> {
+        parse_robots(data, EXAMPLE_GRID_SIZE)
+    }
+
+    fn part1(robots: &Self::Input) -> Answer {
+        let mut robots = robots.clone();
+        robots.iter_mut().for_each(|robot| robot.move_robot(100));
+
+        let robots_per_quadrant = count_robots_in_quadrants(robots);
+        robots_per_quadrant.iter().product::<isize>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{Day14, Robot};
+    use crate::advent_of_code::parse::Span;
+    use crate::advent_of_code::{Point, Solution, Vec2};
+
+    #[test]
+    fn robot_from_string_test() {
+        let robot = Robot::from_line(&Span::line(1, "p=0,4 v=3,-3"), (11, 7)).unwrap();
+        assert_eq!(
+            robot,
+            Robot::new(Point::new(0, 4), Vec2::new(3, -3), (11, 7))
+        );
+    }
+
+    //p=2,4 v=2,-3
+    #[test]
+    fn robot_move_test() {
+        let mut robot = Robot::from_line(&Span::line(1, "p=2,4 v=2,-3"), (11, 7)).unwrap();
+        // 1 second
+        robot.move_robot(1);
+        assert_eq!(robot.position, Point::new(4, 1));
+        // 2 seconds
+        robot.move_robot(1);
+        assert_eq!(robot.position, Point::new(6, 5));
+        // 3 seconds
+        robot.move_robot(1);
+        assert_eq!(robot.position, Point::new(8, 2));
+        // 4 seconds
+        robot.move_robot(1);
+        assert_eq!(robot.position, Point::new(10, 6));
+        // 5 seconds
+        robot.move_robot(1);
+        assert_eq!(robot.position, Point::new(1, 3));
+        // move 5 second in one go
+        let mut robot = Robot::from_line(&Span::line(1, "p=2,4 v=2,-3"), (11, 7)).unwrap();
+        robot.move_robot(5);
+        assert_eq!(robot.position, Point::new(1, 3));
+    }
+
+    #[test]
+    fn res_test() {
+        let d = fs::read_to_string("./input/day14_test.txt").unwrap();
+        let result = Day14::part1(&Day14::parse_example(&d).unwrap());
+        println!("result: {result}");
+        assert_eq!(result, 12.into());
+    }
+}
@@ -0,0 +1,71 @@
+use crate::advent_of_code::parse;
+use crate::advent_of_code::{Answer, Result, Solution};
+
+// A report is safe if the levels are strictly monotonic with steps of at most 3.
+fn is_valid(levels: &[u32]) -> bool {
+    let steps = levels
+        .windows(2)
+        .map(|w| w[0] as i32 - w[1] as i32)
+        .collect::<Vec<i32>>();
+    let monotonic_inc = steps.iter().all(|v| v.is_positive());
+    let monotonic_dec = steps.iter().all(|v| v.is_negative());
+    let step_size = steps.iter().all(|v| v != &0 && v.abs() <= 3);
+    (monotonic_inc || monotonic_dec) && step_size
+}
+
+// The problem dampener tolerates a single bad level, so try the report with each level removed.
+fn is_valid_dampened(levels: &[u32]) -> bool {
+    (0..levels.len()).any(|i| {
+        let mut local_vec = levels.to_vec();
+        local_vec.remove(i);
+        is_valid(&local_vec)
+    })
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<u32>>;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse::lines(data)
+            .map(|line| line.words().map(|level| level.parse()).collect())
+            .collect()
+    }
+
+    fn part1(reports: &Self::Input) -> Answer {
+        reports.iter().filter(|r| is_valid(r)).count().into()
+    }
+
+    fn part2(reports: &Self::Input) -> Answer {
+        reports
+            .iter()
+            .filter(|r| is_valid(r) || is_valid_dampened(r))
+            .count()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::Day2;
+    use crate::advent_of_code::Solution;
+
+    #[test]
+    fn res_test() {
+        let d = fs::read_to_string("./input/day2_1_test.txt").unwrap();
+        let result = Day2::part1(&Day2::parse(&d).unwrap());
+        println!("result: {result}");
+        assert_eq!(result, 2.into());
+    }
+
+    #[test]
+    fn res_test_part2() {
+        let d = fs::read_to_string("./input/day2_1_test.txt").unwrap();
+        let result = Day2::part2(&Day2::parse(&d).unwrap());
+        println!("result: {result}");
+        assert_eq!(result, 4.into());
+    }
+}
@@ -0,0 +1,72 @@
+use regex::Regex;
+
+use crate::advent_of_code::{Answer, Result, Solution};
+
+fn product_from_match(s: &str) -> usize {
+    s.strip_prefix("mul(")
+        .and_then(|inner| inner.strip_suffix(")"))
+        .and_then(|contents| contents.split_once(','))
+        .map(|(f1, f2)| {
+            f1.parse::<usize>()
+                .and_then(|x| f2.parse::<usize>().map(|y| x * y))
+                .expect("Invalid number in multiplication")
+        })
+        .expect("Invalid multiplication instruction")
+}
+
+// The corrupted memory is used as is, the instructions are picked out with a regex.
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = String;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        Ok(data.to_string())
+    }
+
+    fn part1(memory: &Self::Input) -> Answer {
+        let re = Regex::new(r"mul\(\d{1,3},\d{1,3}\)").unwrap();
+        re.find_iter(memory)
+            .map(|mat| product_from_match(mat.as_str()))
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(memory: &Self::Input) -> Answer {
+        let re = Regex::new(r"(mul\(\d{1,3},\d{1,3}\))|(do\(\))|(don't\(\))").unwrap();
+        re.find_iter(memory)
+            .fold((0, 1), |(sum, factor), instruction| {
+                match instruction.as_str() {
+                    "do()" => (sum, 1),
+                    "don't()" => (sum, 0),
+                    inst => (sum + product_from_match(inst) * factor, factor),
+                }
+            })
+            .0
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::Day3;
+    use crate::advent_of_code::Solution;
+
+    #[test]
+    fn res_test() {
+        let d = fs::read_to_string("./input/day3_1_test.txt").unwrap();
+        let result = Day3::part1(&Day3::parse(&d).unwrap());
+        println!("result: {result}");
+        assert_eq!(result, 161.into());
+    }
+
+    #[test]
+    fn res_test_part2() {
+        let d = fs::read_to_string("./input/day3_2_test.txt").unwrap();
+        let result = Day3::part2(&Day3::parse(&d).unwrap());
+        println!("result: {result}");
+        assert_eq!(result, 48.into());
+    }
+}
@@ -0,0 +1,104 @@
+use crate::advent_of_code::{parse, Answer, Direction8, Grid, Result, Solution, Vec2};
+
+// Find all occurances of `XMAS` in a matirx of letters.
+// The direction of the word can be forwards or backwards, top to bottom and diagonal.
+// Thus only 8 possible ways the word can be formed from the "X", those indeces can be determined deterministically.
+
+// Approach:
+// 1. Find all occurances of "X" with the respective coordinates.
+// 2. Walk the 4 letters from the coordinates into each of the 8 directions and check if they match.
+// 3. Count the number of matches.
+
+fn count_xmas(grid: &Grid<char>, start: (usize, usize)) -> usize {
+    Direction8::ALL
+        .iter()
+        .filter(|dir| {
+            grid.ray(start, dir.offset().to_row_col())
+                .take(4)
+                .map(|pos| grid[pos])
+                .eq("XMAS".chars())
+        })
+        .count()
+}
+
+// Part 2 looks for two `MAS` crossing in the shape of an X, so the center is always an "A".
+fn is_x_mas(grid: &Grid<char>, center: (usize, usize)) -> bool {
+    // NW -> center -> SE and NE -> center -> SW
+    let diagonals = [
+        [Direction8::NW, Direction8::SE],
+        [Direction8::NE, Direction8::SW],
+    ];
+
+    diagonals.iter().all(|[from, to]| {
+        let word: Option<String> = [from.offset(), Vec2::ZERO, to.offset()]
+            .iter()
+            .map(|offset| {
+                grid.offset(center, offset.to_row_col())
+                    .map(|pos| grid[pos])
+            })
+            .collect();
+        matches!(word.as_deref(), Some("MAS") | Some("SAM"))
+    })
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid<char>;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse::char_grid(data)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        grid.find_all(&'X')
+            .map(|c| count_xmas(grid, c))
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        grid.find_all(&'A')
+            .filter(|&c| is_x_mas(grid, c))
+            .count()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::Day4;
+    use crate::advent_of_code::Solution;
+
+    #[test]
+    fn small_matrix() {
+        let result = Day4::part1(&Day4::parse("X.X\nABC\nDXF\nGHI").unwrap());
+        println!("result: {result}");
+        assert_eq!(result, 0.into());
+    }
+
+    #[test]
+    fn xmas() {
+        let result = Day4::part1(&Day4::parse("XMAS\nMMAA\nAMAM\nSAMX").unwrap());
+        println!("result: {result}");
+        assert_eq!(result, 4.into());
+    }
+
+    #[test]
+    fn res_test() {
+        let d = fs::read_to_string("./input/day4_1_test.txt").unwrap();
+        let result = Day4::part1(&Day4::parse(&d).unwrap());
+        println!("result: {result}");
+        assert_eq!(result, 18.into());
+    }
+
+    #[test]
+    fn res_test_part2() {
+        let d = fs::read_to_string("./input/day4_2_test.txt").unwrap();
+        let result = Day4::part2(&Day4::parse(&d).unwrap());
+        println!("result: {result}");
+        assert_eq!(result, 9.into());
+    }
+}
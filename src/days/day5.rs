@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+
+use crate::advent_of_code::parse::{self, Span};
+use crate::advent_of_code::{graph, Answer, Error, Result, Solution};
+
+#[derive(Debug)]
+pub struct Manual {
+    rules: Vec<(usize, usize)>,
+    updates: Vec<Update>,
+}
+
+impl Manual {
+    fn rule_set(&self) -> HashSet<(usize, usize)> {
+        self.rules.iter().copied().collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Update {
+    pages: Vec<usize>,
+    line: usize,
+}
+
+impl Update {
+    fn from_line(line: &Span) -> Result<Self> {
+        Ok(Self {
+            pages: line.fields(",")?,
+            line: line.line,
+        })
+    }
+
+    fn is_valid(&self, rules: &HashSet<(usize, usize)>) -> bool {
+        self.pages
+            .windows(2)
+            .all(|page_pair| rules.contains(&(page_pair[0], page_pair[1])))
+    }
+
+    fn get_center(&self) -> usize {
+        self.pages[self.pages.len() / 2]
+    }
+}
+
+// Only the rules between pages of this update matter, the full rule set contains cycles.
+fn order_vector(update: &Update, rules: &[(usize, usize)]) -> Result<Vec<usize>> {
+    graph::topological_sort(update.pages.iter().copied(), rules.iter().copied()).map_err(|cycle| {
+        Error::Cycle {
+            line: update.line,
+            nodes: cycle.iter().map(usize::to_string).collect(),
+        }
+    })
+}
+
+fn parse_manual(data: &str) -> Result<Manual> {
+    let [rules, updates] = parse::sections(data, ["rules", "updates"])?;
+    let rules = rules
+        .iter()
+        .map(|line| {
+            let (left, right) = line.split_once("|")?;
+            Ok((left.parse()?, right.parse()?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let updates = updates
+        .iter()
+        .map(Update::from_line)
+        .collect::<Result<Vec<_>>>()?;
+    // A cycle makes the input unsolvable, so it is reported here and not in part 2.
+    for update in &updates {
+        order_vector(update, &rules)?;
+    }
+    Ok(Manual { rules, updates })
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Manual;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse_manual(data)
+    }
+
+    fn part1(manual: &Self::Input) -> Answer {
+        let rules = manual.rule_set();
+        manual
+            .updates
+            .iter()
+            .filter(|u| u.is_valid(&rules))
+            .map(|u| u.get_center())
+            .sum::<usize>()
+            .into()
+    }
+
+    // only invalid updates, put into the right order
+    fn part2(manual: &Self::Input) -> Answer {
+        let rules = manual.rule_set();
+        manual
+            .updates
+            .iter()
+            .filter(|u| !u.is_valid(&rules))
+            .map(|u| order_vector(u, &manual.rules).expect("parse checks for cycles"))
+            .map(|pages| pages[pages.len() / 2])
+            .sum::<usize>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{order_vector, Day5, Update};
+    use crate::advent_of_code::{Error, Solution};
+
+    fn update(pages: &[usize]) -> Update {
+        Update {
+            pages: pages.to_vec(),
+            line: 3,
+        }
+    }
+
+    #[test]
+    fn order_vector_test() {
+        // Together the rules are a cycle, but every update only uses the ones between its pages.
+        let rules = [(1, 2), (2, 3), (3, 1)];
+        assert_eq!(order_vector(&update(&[2, 1]), &rules).unwrap(), vec![1, 2]);
+        assert_eq!(order_vector(&update(&[3, 2]), &rules).unwrap(), vec![2, 3]);
+        assert_eq!(order_vector(&update(&[1, 3]), &rules).unwrap(), vec![3, 1]);
+
+        let e = order_vector(&update(&[3, 1, 2]), &rules).unwrap_err();
+        assert!(matches!(e, Error::Cycle { line: 3, .. }));
+        assert_eq!(e.to_string(), "line 3: 2, 3, 1 are ordered in a cycle");
+    }
+
+    #[test]
+    fn parse_cycle_test() {
+        let e = Day5::parse("1|2\n2|3\n3|1\n\n1,2\n1,2,3\n").unwrap_err();
+        assert!(matches!(e, Error::Cycle { line: 6, .. }));
+    }
+
+    #[test]
+    fn res_test() {
+        let d = fs::read_to_string("./input/day5_1_test.txt").unwrap();
+        let result = Day5::part1(&Day5::parse(&d).unwrap());
+        println!("result: {result}");
+        assert_eq!(result, 143.into());
+    }
+
+    #[test]
+    fn res_test_part2() {
+        let d = fs::read_to_string("./input/day5_1_test.txt").unwrap();
+        let result = Day5::part2(&Day5::parse(&d).unwrap());
+        println!("result: {result}");
+        assert_eq!(result, 123.into());
+    }
+}
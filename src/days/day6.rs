@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+
+use crate::advent_of_code::{parse, Answer, Direction4, Error, Grid, Result, Solution};
+
+// `extra` is an obstacle that is not on the map, the one placed for part 2.
+fn is_obstacle_at(grid: &Grid<char>, pos: (usize, usize), extra: Option<(usize, usize)>) -> bool {
+    grid[pos] == '#' || Some(pos) == extra
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Patrol {
+    LeavesMap,
+    Loops,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Guard {
+    pos: (usize, usize),
+    direction: Direction4,
+}
+
+impl Guard {
+    fn from_grid(grid: &Grid<char>) -> Result<Self> {
+        let (pos, arrow) = grid
+            .find_any_of(&['^', '>', 'v', '<'])
+            .ok_or_else(|| Error::MissingSection("the guard".to_string()))?;
+        let direction = Direction4::from_arrow(arrow).expect("only arrows are searched for");
+        Ok(Self { pos, direction })
+    }
+
+    fn take_step(
+        &mut self,
+        grid: &Grid<char>,
+        extra: Option<(usize, usize)>,
+    ) -> Option<(usize, usize)> {
+        let offset = self.direction.offset().to_row_col();
+
+        grid.offset(self.pos, offset).map(|valid_pos| {
+            if is_obstacle_at(grid, valid_pos, extra) {
+                self.direction = self.direction.turn_right();
+                self.pos // Stay in the same position if there's an obstacle
+            } else {
+                self.pos = valid_pos;
+                self.pos
+            }
+        })
+    }
+
+    // The guard loops once it turns at the same place into the same direction again.
+    // A loop always has turns, so only those need to be remembered.
+    fn patrol(mut self, grid: &Grid<char>, extra: Option<(usize, usize)>) -> Patrol {
+        let mut turns: HashSet<((usize, usize), Direction4)> = HashSet::new();
+        loop {
+            let direction = self.direction;
+            if self.take_step(grid, extra).is_none() {
+                return Patrol::LeavesMap;
+            }
+            if self.direction != direction && !turns.insert((self.pos, self.direction)) {
+                return Patrol::Loops;
+            }
+        }
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = (Grid<char>, Guard);
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        let grid = parse::char_grid(data)?;
+        let guard = Guard::from_grid(&grid)?;
+        Ok((grid, guard))
+    }
+
+    fn part1((grid, guard): &Self::Input) -> Answer {
+        let mut guard = *guard;
+        let mut visited_posistions = HashSet::from([guard.pos]);
+        while let Some(pos) = guard.take_step(grid, None) {
+            visited_posistions.insert(pos);
+        }
+
+        visited_posistions.len().into()
+    }
+
+    // A new obstacle only changes something on the path the guard walks. Placing it
+    // where the guard is about to enter a cell for the first time, the patrol up to
+    // there stays the same, so the check can start right in front of it.
+    fn part2((grid, guard): &Self::Input) -> Answer {
+        let mut guard = *guard;
+        let mut tried = HashSet::from([guard.pos]); // not on the guard itself
+        let mut loops = 0;
+        loop {
+            let before = guard;
+            let Some(pos) = guard.take_step(grid, None) else {
+                break;
+            };
+            if pos != before.pos
+                && tried.insert(pos)
+                && before.patrol(grid, Some(pos)) == Patrol::Loops
+            {
+                loops += 1;
+            }
+        }
+        loops.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::Day6;
+    use crate::advent_of_code::Solution;
+
+    #[test]
+    fn res_test() {
+        let d = fs::read_to_string("./input/day6_1_test.txt").unwrap();
+        let result = Day6::part1(&Day6::parse(&d).unwrap());
+        println!("result: {result}");
+        assert_eq!(result, 41.into());
+    }
+
+    #[test]
+    fn res_test_part2() {
+        let d = fs::read_to_string("./input/day6_1_test.txt").unwrap();
+        let result = Day6::part2(&Day6::parse(&d).unwrap());
+        println!("result: {result}");
+        assert_eq!(result, 6.into());
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::HashSet;
+
+use crate::advent_of_code::{parse, Answer, Result, Solution};
+
+fn calculate_results(
+    v1: usize,
+    v2: usize,
+    operations: Vec<fn(usize, usize) -> usize>,
+) -> HashSet<usize> {
+    let mut results = HashSet::new();
+    for operation in &operations {
+        results.insert(operation(v1, v2));
+    }
+    results
+}
+
+fn parse_lines(data: &str) -> Result<Vec<(usize, Vec<usize>)>> {
+    parse::lines(data)
+        .map(|line| {
+            // The test value before the colon, the numbers after it
+            let (result, numbers) = line.split_once(":")?;
+            let key = result.parse()?;
+            let values = numbers.words().map(|n| n.parse()).collect::<Result<_>>()?;
+            Ok((key, values))
+        })
+        .collect()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<(usize, Vec<usize>)>;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse_lines(data)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let operators: Vec<fn(usize, usize) -> usize> = vec![
+            |a, b| a + b, // Addition
+            |a, b| a * b, // Multiplication
+        ];
+
+        let mut sum: usize = 0;
+
+        for (target, values) in input.iter() {
+            let mut values = values.clone();
+
+            // prepare
+            let mut results: HashSet<usize> = HashSet::new();
+            results.insert(values[0]);
+
+            values.drain(1..).for_each(|v1| {
+                let mut prev = results.clone();
+                results.clear();
+                prev.drain().for_each(|v2| {
+                    calculate_results(v1, v2, operators.clone())
+                        .iter()
+                        .filter(|&&v| v <= *target)
+                        .for_each(|v| {
+                            results.insert(*v);
+                        });
+                });
+            });
+
+            if results.contains(target) {
+                sum += *target;
+            }
+        }
+        sum.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::Day7;
+    use crate::advent_of_code::Solution;
+
+    #[test]
+    fn res_test() {
+        let d = fs::read_to_string("./input/day7_test.txt").unwrap();
+        let result = Day7::part1(&Day7::parse(&d).unwrap());
+        println!("result: {result}");
+        assert_eq!(result, 3749.into());
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::advent_of_code::{parse, Answer, Grid, Point, Result, Solution};
+
+type Pair = ((usize, usize), (usize, usize));
+
+fn generate_antenna_pairs(
+    antennas: &HashMap<char, HashSet<(usize, usize)>>,
+) -> HashMap<char, Vec<Pair>> {
+    let mut pairs_per_frequency = HashMap::new();
+
+    for (&frequency, positions) in antennas {
+        // Collect all pairs of antenna positions
+        let pairs: Vec<_> = positions
+            .iter()
+            .flat_map(|&a| {
+                positions
+                    .iter()
+                    .filter(move |&&b| a < b) // Avoid duplicate pairs and self-pairs
+                    .map(move |&b| (a, b))
+            })
+            .collect();
+
+        pairs_per_frequency.insert(frequency, pairs);
+    }
+
+    pairs_per_frequency
+}
+
+// The two antinodes of a pair lie on the line through both antennas,
+// one antenna distance beyond each of them.
+fn antinodes_from_pair(grid: &Grid<char>, pair: &Pair, antinodes: &mut HashSet<(usize, usize)>) {
+    let (a, b) = (Point::from_grid(pair.0), Point::from_grid(pair.1));
+    let distance = b - a;
+
+    for node in [a - distance, b + distance] {
+        if let Some(n) = grid.check_bound(node.to_row_col()) {
+            antinodes.insert(n);
+        }
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Grid<char>;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse::char_grid(data)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        let mut antennas: HashMap<char, HashSet<(usize, usize)>> = HashMap::new();
+
+        for (pos, &c) in grid.iter() {
+            if c.is_ascii_alphanumeric() {
+                antennas.entry(c).or_default().insert(pos);
+            }
+        }
+
+        let pairs = generate_antenna_pairs(&antennas);
+
+        let mut antinodes = HashSet::new();
+        pairs.iter().for_each(|(_freq, pairs)| {
+            pairs
+                .iter()
+                .for_each(|pair| antinodes_from_pair(grid, pair, &mut antinodes));
+        });
+
+        antinodes.len().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::Day8;
+    use crate::advent_of_code::Solution;
+
+    #[test]
+    fn res_test() {
+        let d = fs::read_to_string("./input/day8_test.txt").unwrap();
+        let result = Day8::part1(&Day8::parse(&d).unwrap());
+        println!("result: {result}");
+        assert_eq!(result, 14.into());
+    }
+}
@@ -0,0 +1,247 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day14;
+pub mod day17;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+
+use self::day1::Day1;
+use self::day10::Day10;
+use self::day11::Day11;
+use self::day14::Day14;
+use self::day17::Day17;
+use self::day2::Day2;
+use self::day3::Day3;
+use self::day4::Day4;
+use self::day5::Day5;
+use self::day6::Day6;
+use self::day7::Day7;
+use self::day8::Day8;
+use crate::advent_of_code::answers::same_input;
+use crate::advent_of_code::{Answer, PhaseTimes, Result, Solution};
+
+/// A registered day with its real input and the examples for both parts.
+pub struct Puzzle {
+    pub day: u8,
+    pub input: &'static str,
+    pub examples: [&'static str; 2],
+    pub solve: fn(&str, u8) -> Result<Answer>,
+    pub solve_example: fn(&str, u8) -> Result<Answer>,
+    pub time_phases: fn(&str) -> Result<PhaseTimes>,
+    pub time_example_phases: fn(&str) -> Result<PhaseTimes>,
+}
+
+impl Puzzle {
+    pub fn is_example(&self, input: &str) -> bool {
+        self.examples
+            .iter()
+            .any(|example| same_input(example, input))
+    }
+
+    /// How to solve `input`, the examples are parsed with `Solution::parse_example`.
+    pub fn solver(&self, input: &str) -> fn(&str, u8) -> Result<Answer> {
+        match self.is_example(input) {
+            true => self.solve_example,
+            false => self.solve,
+        }
+    }
+
+    /// How to time `input`, see `solver`.
+    pub fn timer(&self, input: &str) -> fn(&str) -> Result<PhaseTimes> {
+        match self.is_example(input) {
+            true => self.time_example_phases,
+            false => self.time_phases,
+        }
+    }
+}
+
+/// Where `aoc fetch` puts the input of a day, every registered day reads it from there.
+pub fn input_file(day: u8) -> String {
+    format!("./input/day{day}.txt")
+}
+
+/// All days with a solution, in order.
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle {
+        day: 1,
+        input: "./input/day1.txt",
+        examples: ["./input/day1_1_test.txt", "./input/day1_1_test.txt"],
+        solve: Day1::solve,
+        solve_example: Day1::solve_example,
+        time_phases: Day1::time_phases,
+        time_example_phases: Day1::time_example_phases,
+    },
+    Puzzle {
+        day: 2,
+        input: "./input/day2.txt",
+        examples: ["./input/day2_1_test.txt", "./input/day2_1_test.txt"],
+        solve: Day2::solve,
+        solve_example: Day2::solve_example,
+        time_phases: Day2::time_phases,
+        time_example_phases: Day2::time_example_phases,
+    },
+    Puzzle {
+        day: 3,
+        input: "./input/day3.txt",
+        examples: ["./input/day3_1_test.txt", "./input/day3_2_test.txt"],
+        solve: Day3::solve,
+        solve_example: Day3::solve_example,
+        time_phases: Day3::time_phases,
+        time_example_phases: Day3::time_example_phases,
+    },
+    Puzzle {
+        day: 4,
+        input: "./input/day4.txt",
+        examples: ["./input/day4_1_test.txt", "./input/day4_2_test.txt"],
+        solve: Day4::solve,
+        solve_example: Day4::solve_example,
+        time_phases: Day4::time_phases,
+        time_example_phases: Day4::time_example_phases,
+    },
+    Puzzle {
+        day: 5,
+        input: "./input/day5.txt",
+        examples: ["./input/day5_1_test.txt", "./input/day5_1_test.txt"],
+        solve: Day5::solve,
+        solve_example: Day5::solve_example,
+        time_phases: Day5::time_phases,
+        time_example_phases: Day5::time_example_phases,
+    },
+    Puzzle {
+        day: 6,
+        input: "./input/day6.txt",
+        examples: ["./input/day6_1_test.txt", "./input/day6_1_test.txt"],
+        solve: Day6::solve,
+        solve_example: Day6::solve_example,
+        time_phases: Day6::time_phases,
+        time_example_phases: Day6::time_example_phases,
+    },
+    Puzzle {
+        day: 7,
+        input: "./input/day7.txt",
+        examples: ["./input/day7_test.txt", "./input/day7_test.txt"],
+        solve: Day7::solve,
+        solve_example: Day7::solve_example,
+        time_phases: Day7::time_phases,
+        time_example_phases: Day7::time_example_phases,
+    },
+    Puzzle {
+        day: 8,
+        input: "./input/day8.txt",
+        examples: ["./input/day8_test.txt", "./input/day8_test.txt"],
+        solve: Day8::solve,
+        solve_example: Day8::solve_example,
+        time_phases: Day8::time_phases,
+        time_example_phases: Day8::time_example_phases,
+    },
+    Puzzle {
+        day: 10,
+        input: "./input/day10.txt",
+        examples: ["./input/day10_test.txt", "./input/day10_test.txt"],
+        solve: Day10::solve,
+        solve_example: Day10::solve_example,
+        time_phases: Day10::time_phases,
+        time_example_phases: Day10::time_example_phases,
+    },
+    Puzzle {
+        day: 11,
+        input: "./input/day11.txt",
+        examples: ["./input/day11_test.txt", "./input/day11_test.txt"],
+        solve: Day11::solve,
+        solve_example: Day11::solve_example,
+        time_phases: Day11::time_phases,
+        time_example_phases: Day11::time_example_phases,
+    },
+    // The example is played on a smaller grid, see `Day14::parse_example`.
+    Puzzle {
+        day: 14,
+        input: "./input/day14.txt",
+        examples: ["./input/day14_test.txt", "./input/day14_test.txt"],
+        solve: Day14::solve,
+        solve_example: Day14::solve_example,
+        time_phases: Day14::time_phases,
+        time_example_phases: Day14::time_example_phases,
+    },
+    Puzzle {
+        day: 17,
+        input: "./input/day17.txt",
+        examples: ["./input/day17_test.txt", "./input/day17_test.txt"],
+        solve: Day17::solve,
+        solve_example: Day17::solve_example,
+        time_phases: Day17::time_phases,
+        time_example_phases: Day17::time_example_phases,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{input_file, PUZZLES};
+    use crate::advent_of_code::answers::{load_answers, ANSWERS_FILE};
+    use crate::advent_of_code::Reader;
+
+    #[test]
+    fn input_file_test() {
+        for puzzle in PUZZLES {
+            assert_eq!(puzzle.input, input_file(puzzle.day));
+        }
+    }
+
+    #[test]
+    fn is_example_test() {
+        let day14 = PUZZLES.iter().find(|p| p.day == 14).unwrap();
+        assert!(day14.is_example("input/day14_test.txt"));
+        assert!(day14.is_example("./input/day14_test.txt"));
+        assert!(!day14.is_example("./input/day14.txt"));
+    }
+
+    // Runs every solution on every input with a known answer, see `input/answers.txt`.
+    #[test]
+    fn known_answers_test() {
+        let answers = load_answers(ANSWERS_FILE).unwrap_or_else(|e| panic!("{e}"));
+        assert!(!answers.is_empty(), "no answers in {ANSWERS_FILE}");
+
+        let mut failures = Vec::new();
+        for known in &answers {
+            let Some(puzzle) = PUZZLES.iter().find(|p| p.day == known.day) else {
+                failures.push(format!("day {}: no solution registered", known.day));
+                continue;
+            };
+            let data = match Reader::read_to_string(&known.input) {
+                Ok(data) => data,
+                Err(e) => {
+                    failures.push(format!("day {} part {}: {e}", known.day, known.part));
+                    continue;
+                }
+            };
+            let result = match (puzzle.solver(&known.input))(&data, known.part) {
+                Ok(answer) => answer.to_string(),
+                Err(e) => {
+                    failures.push(format!(
+                        "day {} part {}: {}",
+                        known.day,
+                        known.part,
+                        e.in_file(&known.input)
+                    ));
+                    continue;
+                }
+            };
+            println!(
+                "day {} part {} on {}: {result}",
+                known.day, known.part, known.input
+            );
+            if result != known.answer {
+                failures.push(format!(
+                    "day {} part {} on {}: got {result}, expected {}",
+                    known.day, known.part, known.input, known.answer
+                ));
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
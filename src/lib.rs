@@ -0,0 +1,4 @@
+// Shared helpers and the solutions of all days, used by the `aoc` runner and the day binaries.
+
+pub mod advent_of_code;
+pub mod days;
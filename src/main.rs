@@ -1,3 +1,675 @@
-fn main() {
-    println!("Hello, world!");
+// The `aoc` runner: one binary that can run every registered puzzle solution.
+//
+// cargo run -- 7 --part 1 --input input/day7.txt
+// cargo run -- 7 --example
+// cargo run -- --all
+// cargo run --release -- bench --all --iterations 20 --save bench.tsv --compare bench_old.tsv
+// cargo run -- new 15
+// AOC_SESSION=... cargo run -- fetch 15
+// AOC_SESSION=... cargo run -- submit 15 1
+// cargo run -- extract 15 day15.html --block 1 --answer 2
+
+use std::{
+    any::Any,
+    env, fs, panic,
+    path::Path,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc_2024::advent_of_code::answers::{
+    load_answers, record_answer, verdict, KnownAnswer, Verdict, ANSWERS_FILE,
+};
+use aoc_2024::advent_of_code::bench::{self, Phase, Record};
+use aoc_2024::advent_of_code::client::{fetch_input, Client, Fetched};
+use aoc_2024::advent_of_code::extract;
+use aoc_2024::advent_of_code::scaffold;
+use aoc_2024::advent_of_code::submit::{submit, Outcome, HISTORY_FILE};
+use aoc_2024::advent_of_code::{Answer, Reader};
+use aoc_2024::days::{input_file, Puzzle, PUZZLES};
+
+const USAGE: &str = "usage: aoc <day> [--part <1|2>] [--input <file> | --example]
+       aoc --all [--example]
+       aoc bench <day> | --all [--example] [--iterations <n>] [--save <file>]
+                               [--compare <file>] [--tolerance <percent>]
+       aoc new <day>
+       aoc fetch <day>
+       aoc submit <day> <part>
+       aoc extract <day> <page.html> [--block <n>] [--answer <n>] [--part <1|2>]";
+
+const DEFAULT_ITERATIONS: usize = 10;
+const DEFAULT_TOLERANCE: f64 = 20.0;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    #[default]
+    Solve,
+    Bench,
+    New,
+    Fetch,
+    Submit,
+    Extract,
+}
+
+#[derive(Debug, Default)]
+struct Args {
+    command: Command,
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<String>,
+    example: bool,
+    all: bool,
+    iterations: Option<usize>,
+    save: Option<String>,
+    compare: Option<String>,
+    tolerance: Option<f64>,
+    page: Option<String>,
+    block: Option<usize>,
+    answer: Option<usize>,
+}
+
+impl Args {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                command @ ("bench" | "new" | "fetch" | "submit" | "extract")
+                    if parsed.command == Command::Solve && parsed.day.is_none() =>
+                {
+                    parsed.command = match command {
+                        "bench" => Command::Bench,
+                        "new" => Command::New,
+                        "fetch" => Command::Fetch,
+                        "submit" => Command::Submit,
+                        _ => Command::Extract,
+                    }
+                }
+                "--all" => parsed.all = true,
+                "--example" => parsed.example = true,
+                "--part" | "-p" => {
+                    let part = args.next().ok_or("--part needs a value")?;
+                    match part.as_str() {
+                        "1" => parsed.part = Some(1),
+                        "2" => parsed.part = Some(2),
+                        _ => return Err(format!("invalid part '{part}', expected 1 or 2")),
+                    }
+                }
+                "--input" | "-i" => {
+                    parsed.input = Some(args.next().ok_or("--input needs a file")?);
+                }
+                "--iterations" | "-n" => {
+                    let n = args.next().ok_or("--iterations needs a value")?;
+                    let n = n
+                        .parse()
+                        .ok()
+                        .filter(|&n| n > 0)
+                        .ok_or(format!("invalid number of iterations '{n}'"))?;
+                    parsed.iterations = Some(n);
+                }
+                "--save" => parsed.save = Some(args.next().ok_or("--save needs a file")?),
+                "--compare" => {
+                    parsed.compare = Some(args.next().ok_or("--compare needs a file")?);
+                }
+                "--tolerance" => {
+                    let t = args.next().ok_or("--tolerance needs a percentage")?;
+                    let t = t
+                        .parse()
+                        .ok()
+                        .filter(|&t: &f64| t >= 0.0)
+                        .ok_or(format!("invalid tolerance '{t}'"))?;
+                    parsed.tolerance = Some(t);
+                }
+                flag @ ("--block" | "--answer") => {
+                    let n = args.next().ok_or(format!("{flag} needs a number"))?;
+                    let n = n
+                        .parse()
+                        .ok()
+                        .filter(|&n| n > 0)
+                        .ok_or(format!("invalid number '{n}', they start at 1"))?;
+                    match flag {
+                        "--block" => parsed.block = Some(n),
+                        _ => parsed.answer = Some(n),
+                    }
+                }
+                page if parsed.command == Command::Extract
+                    && parsed.day.is_some()
+                    && parsed.page.is_none() =>
+                {
+                    parsed.page = Some(page.to_string());
+                }
+                part if parsed.command == Command::Submit
+                    && parsed.day.is_some()
+                    && parsed.part.is_none() =>
+                {
+                    match part {
+                        "1" => parsed.part = Some(1),
+                        "2" => parsed.part = Some(2),
+                        _ => return Err(format!("invalid part '{part}', expected 1 or 2")),
+                    }
+                }
+                day if parsed.day.is_none() && !day.starts_with('-') => {
+                    let day = day
+                        .parse::<u8>()
+                        .ok()
+                        .filter(|day| (1..=25).contains(day))
+                        .ok_or(format!("invalid day '{day}', expected 1 to 25"))?;
+                    parsed.day = Some(day);
+                }
+                other => return Err(format!("unexpected argument '{other}'")),
+            }
+        }
+
+        match (parsed.all, parsed.day) {
+            (true, Some(_)) => Err("either give a day or --all, not both".into()),
+            (false, None) => Err("missing day".into()),
+            (true, None) if parsed.input.is_some() || parsed.part.is_some() => {
+                Err("--input and --part can only be used for a single day".into())
+            }
+            _ if parsed.input.is_some() && parsed.example => {
+                Err("either give --input or --example, not both".into())
+            }
+            _ if parsed.command == Command::Submit
+                && (parsed.part.is_none() || parsed.input.is_some() || parsed.example) =>
+            {
+                Err("submit needs the day and the part, and always uses the real input".into())
+            }
+            _ if matches!(parsed.command, Command::New | Command::Fetch)
+                && (parsed.all
+                    || parsed.part.is_some()
+                    || parsed.input.is_some()
+                    || parsed.example) =>
+            {
+                Err("new and fetch only take the day".into())
+            }
+            _ if parsed.command == Command::Extract
+                && (parsed.page.is_none()
+                    || parsed.all
+                    || parsed.input.is_some()
+                    || parsed.example) =>
+            {
+                Err("extract needs the day and the saved puzzle page".into())
+            }
+            _ if parsed.command != Command::Extract
+                && (parsed.block.is_some() || parsed.answer.is_some()) =>
+            {
+                Err("--block and --answer only work with extract".into())
+            }
+            _ if parsed.command == Command::Bench && parsed.part.is_some() => {
+                Err("bench always times parse and both parts, --part is not supported".into())
+            }
+            _ if parsed.command != Command::Bench
+                && (parsed.iterations.is_some()
+                    || parsed.save.is_some()
+                    || parsed.compare.is_some()
+                    || parsed.tolerance.is_some()) =>
+            {
+                Err("--iterations, --save, --compare and --tolerance only work with bench".into())
+            }
+            _ => Ok(parsed),
+        }
+    }
+
+    fn selects(&self, puzzle: &Puzzle) -> bool {
+        self.all || Some(puzzle.day) == self.day
+    }
+
+    fn input<'a>(&'a self, puzzle: &'a Puzzle, part: u8) -> &'a str {
+        match (&self.input, self.example) {
+            (Some(input), _) => input.as_str(),
+            (None, true) => puzzle.examples[part as usize - 1],
+            (None, false) => puzzle.input,
+        }
+    }
+
+    fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+// Malformed input comes back as an error, a panic is a bug in the solution itself.
+fn panicked(input: &str, payload: Box<dyn Any + Send>) -> String {
+    let message = payload
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| payload.downcast_ref::<&str>().copied());
+    match message {
+        Some(message) => format!("the solution panicked on {input}: {message}"),
+        None => format!("the solution panicked on {input}"),
+    }
+}
+
+fn run(puzzle: &Puzzle, part: u8, input: &str) -> Result<(Answer, Duration), String> {
+    let data = Reader::read_to_string(input).map_err(|e| e.to_string())?;
+    let start = Instant::now();
+    let answer = panic::catch_unwind(|| (puzzle.solver(input))(&data, part))
+        .map_err(|payload| panicked(input, payload))?
+        .map_err(|e| e.in_file(input).to_string())?;
+    Ok((answer, start.elapsed()))
+}
+
+// Solves the selected parts and prints the answers, returns false if any of them failed.
+fn solve(args: &Args, puzzles: &[&Puzzle]) -> bool {
+    let answers = match load_answers(ANSWERS_FILE) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return false;
+        }
+    };
+
+    let mut ok = true;
+    for puzzle in puzzles {
+        for part in args.parts() {
+            let input = args.input(puzzle, part);
+            match run(puzzle, part, input) {
+                // Only complain about a missing part if it was asked for explicitly.
+                Ok((Answer::Unsolved, _)) if args.part.is_none() => {}
+                Ok((Answer::Unsolved, _)) => {
+                    eprintln!("day {} part {part}: not solved yet", puzzle.day);
+                    ok = false;
+                }
+                Ok((answer, elapsed)) => {
+                    let mark = match verdict(&answers, puzzle.day, part, input, &answer) {
+                        Verdict::Verified => " verified".to_string(),
+                        Verdict::Wrong { expected } => {
+                            ok = false;
+                            format!(" WRONG, expected {expected}")
+                        }
+                        Verdict::Unknown => String::new(),
+                    };
+                    println!(
+                        "day {} part {part}: {answer} ({elapsed:.2?}){mark}",
+                        puzzle.day
+                    );
+                }
+                Err(e) => {
+                    eprintln!("day {} part {part}: failed, {e}", puzzle.day);
+                    ok = false;
+                }
+            }
+        }
+    }
+    ok
+}
+
+fn measure(puzzle: &Puzzle, input: &str, iterations: usize) -> Result<Vec<Record>, String> {
+    let data = Reader::read_to_string(input).map_err(|e| e.to_string())?;
+    panic::catch_unwind(|| bench::measure(puzzle.day, puzzle.timer(input), &data, iterations))
+        .map_err(|payload| panicked(input, payload))?
+        .map_err(|e| e.in_file(input).to_string())
+}
+
+// Times parse and part 1 on the input of part 1. Part 2 is timed on its own input if it
+// has a different one, like the examples of days 3 and 4.
+fn measure_day(args: &Args, puzzle: &Puzzle, iterations: usize) -> Result<Vec<Record>, String> {
+    let (first, second) = (args.input(puzzle, 1), args.input(puzzle, 2));
+    let mut records = measure(puzzle, first, iterations)?;
+    if second != first {
+        records.retain(|r| r.phase != Phase::Part2);
+        let part2 = measure(puzzle, second, iterations)?;
+        records.extend(part2.into_iter().filter(|r| r.phase == Phase::Part2));
+    }
+    Ok(records)
+}
+
+// Times every phase of the selected days, returns false if one failed or got slower.
+fn benchmark(args: &Args, puzzles: &[&Puzzle]) -> bool {
+    let iterations = args.iterations.unwrap_or(DEFAULT_ITERATIONS);
+    let mut ok = true;
+    let mut records = Vec::new();
+    for puzzle in puzzles {
+        match measure_day(args, puzzle, iterations) {
+            Ok(measured) => {
+                for r in &measured {
+                    println!(
+                        "day {} {}: min {:.2?}, median {:.2?}, max {:.2?} ({iterations} runs)",
+                        r.day, r.phase, r.stats.min, r.stats.median, r.stats.max
+                    );
+                }
+                records.extend(measured);
+            }
+            Err(e) => {
+                eprintln!("day {}: failed, {e}", puzzle.day);
+                ok = false;
+            }
+        }
+    }
+
+    if let Some(file) = &args.save {
+        if let Err(e) = fs::write(file, bench::write_report(&records)) {
+            eprintln!("error: could not write {file}: {e}");
+            ok = false;
+        }
+    }
+
+    if let Some(file) = &args.compare {
+        let before = match Reader::read_to_string(file).and_then(|d| bench::parse_report(&d)) {
+            Ok(before) => before,
+            Err(e) => {
+                eprintln!("error: {e}");
+                return false;
+            }
+        };
+        let tolerance = args.tolerance.unwrap_or(DEFAULT_TOLERANCE);
+        let regressions = bench::regressions(&before, &records, tolerance / 100.0);
+        for regression in &regressions {
+            eprintln!("regression: {regression}");
+        }
+        match regressions.is_empty() {
+            true => println!("no regressions compared to {file} (tolerance {tolerance}%)"),
+            false => ok = false,
+        }
+    }
+    ok
+}
+
+fn new_day(day: u8) -> ExitCode {
+    match scaffold::new_day(Path::new("."), day) {
+        Ok(files) => {
+            for file in files {
+                println!("wrote {}", file.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn fetch(day: u8) -> ExitCode {
+    let input = input_file(day);
+    let fetched = Client::from_env(Path::new("."))
+        .and_then(|client| fetch_input(&client, day, Path::new(&input)));
+    match fetched {
+        Ok(Fetched::Cached) => println!("{input} is already there, not downloading it again"),
+        Ok(Fetched::Downloaded) => println!("downloaded {input}"),
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn submit_answer(day: u8, part: u8) -> ExitCode {
+    let Some(puzzle) = PUZZLES.iter().find(|p| p.day == day) else {
+        eprintln!("error: no solution registered for day {day}");
+        return ExitCode::FAILURE;
+    };
+    let answer = match run(puzzle, part, puzzle.input) {
+        Ok((Answer::Unsolved, _)) => {
+            eprintln!("error: day {day} part {part} is not solved yet");
+            return ExitCode::FAILURE;
+        }
+        Ok((answer, _)) => answer.to_string(),
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("day {day} part {part}: submitting {answer}");
+    let outcome = Client::from_env(Path::new("."))
+        .and_then(|client| submit(&client, Path::new(HISTORY_FILE), day, part, &answer));
+    match outcome {
+        Ok(Outcome::Correct) => {
+            println!("correct!");
+            // Keep it as regression test, unless it is known already.
+            let known = KnownAnswer {
+                day,
+                part,
+                input: puzzle.input.trim_start_matches("./").to_string(),
+                answer,
+            };
+            let answers = load_answers(ANSWERS_FILE).unwrap_or_default();
+            if !answers.iter().any(|a| a.is_for(day, part, puzzle.input)) {
+                if let Err(e) = record_answer(ANSWERS_FILE, &known) {
+                    eprintln!("error: {e}");
+                    return ExitCode::FAILURE;
+                }
+                println!("added it to {ANSWERS_FILE}");
+            }
+            return ExitCode::SUCCESS;
+        }
+        Ok(Outcome::TooHigh) => println!("wrong, the answer is too high"),
+        Ok(Outcome::TooLow) => println!("wrong, the answer is too low"),
+        Ok(Outcome::Wrong) => println!("wrong"),
+        Ok(Outcome::RateLimited(Some(wait))) => {
+            println!("submitted too recently, wait {wait:?} before trying again")
+        }
+        Ok(Outcome::RateLimited(None)) => println!("submitted too recently, wait a bit"),
+        Ok(Outcome::WrongLevel) => println!("this part is solved already or not unlocked yet"),
+        Err(e) => eprintln!("error: {e}"),
+    }
+    ExitCode::FAILURE
+}
+
+// Registered days keep their example file name, new ones use dayN_test.txt. An answer
+// needs a registered day, the known answers test fails on answers without a solution.
+fn example_input(day: u8, part: u8, answer: bool) -> Result<String, String> {
+    match PUZZLES.iter().find(|p| p.day == day) {
+        Some(puzzle) => Ok(puzzle.examples[part as usize - 1].to_string()),
+        None if answer => Err(format!(
+            "day {day} has no solution yet, create it with `aoc new {day}` first"
+        )),
+        None => Ok(format!("./input/day{day}_test.txt")),
+    }
+}
+
+// Lists the examples on a saved puzzle page, or keeps the picked ones.
+fn extract_example(args: &Args, day: u8, page: &str) -> ExitCode {
+    let html = match Reader::read_to_string(page) {
+        Ok(html) => html,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let blocks = extract::code_blocks(&html);
+    let values = extract::emphasized(&html);
+
+    if args.block.is_none() && args.answer.is_none() {
+        for (i, block) in blocks.iter().enumerate() {
+            println!("block {}:\n{}", i + 1, block.trim_end());
+        }
+        for (i, value) in values.iter().enumerate() {
+            println!("answer {}: {value}", i + 1);
+        }
+        if blocks.is_empty() && values.is_empty() {
+            println!("no examples found in {page}");
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let part = args.part.unwrap_or(1);
+    let example = match example_input(day, part, args.answer.is_some()) {
+        Ok(example) => example,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Some(n) = args.block {
+        let Some(block) = blocks.get(n - 1) else {
+            eprintln!("error: there is no block {n}, {page} has {}", blocks.len());
+            return ExitCode::FAILURE;
+        };
+        if let Err(e) = extract::write_example(Path::new(&example), block) {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+        println!("wrote {example}");
+    }
+
+    if let Some(n) = args.answer {
+        let Some(value) = values.get(n - 1) else {
+            eprintln!("error: there is no answer {n}, {page} has {}", values.len());
+            return ExitCode::FAILURE;
+        };
+        let answers = load_answers(ANSWERS_FILE).unwrap_or_default();
+        if let Some(known) = answers.iter().find(|a| a.is_for(day, part, &example)) {
+            eprintln!(
+                "error: {ANSWERS_FILE} already has {} for {example}",
+                known.answer
+            );
+            return ExitCode::FAILURE;
+        }
+        let known = KnownAnswer {
+            day,
+            part,
+            input: example.trim_start_matches("./").to_string(),
+            answer: value.clone(),
+        };
+        if let Err(e) = record_answer(ANSWERS_FILE, &known) {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+        println!("added day {day} part {part}: {value} to {ANSWERS_FILE}");
+    }
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {e}\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match (args.command, args.day, args.part) {
+        (Command::New, Some(day), _) => return new_day(day),
+        (Command::Fetch, Some(day), _) => return fetch(day),
+        (Command::Submit, Some(day), Some(part)) => return submit_answer(day, part),
+        (Command::Extract, Some(day), _) => {
+            if let Some(page) = &args.page {
+                return extract_example(&args, day, page);
+            }
+        }
+        _ => {}
+    }
+
+    let selected: Vec<&Puzzle> = PUZZLES.iter().filter(|p| args.selects(p)).collect();
+    if selected.is_empty() {
+        eprintln!("error: no solution registered for the selected day");
+        return ExitCode::FAILURE;
+    }
+
+    let ok = match args.command {
+        Command::Bench => benchmark(&args, &selected),
+        _ => solve(&args, &selected),
+    };
+    match ok {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{example_input, Args, Command, PUZZLES};
+
+    fn args(s: &str) -> Result<Args, String> {
+        Args::parse(s.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn parse_args_test() {
+        let parsed = args("7 --part 1 --input input/day7.txt").unwrap();
+        assert_eq!(parsed.day, Some(7));
+        assert_eq!(parsed.part, Some(1));
+        assert_eq!(parsed.input.as_deref(), Some("input/day7.txt"));
+        assert!(args("--all").unwrap().all);
+        assert!(args("").is_err());
+        assert!(args("26").is_err());
+        assert!(args("7 --part 3").is_err());
+        assert!(args("7 --all").is_err());
+        assert!(args("--all --input input/day7.txt").is_err());
+        assert!(args("7 --iterations 5").is_err());
+    }
+
+    #[test]
+    fn parse_bench_args_test() {
+        let parsed = args("bench --all --iterations 5 --save new.tsv --compare old.tsv").unwrap();
+        assert_eq!(parsed.command, Command::Bench);
+        assert!(parsed.all);
+        assert_eq!(parsed.iterations, Some(5));
+        assert_eq!(parsed.save.as_deref(), Some("new.tsv"));
+        assert_eq!(parsed.compare.as_deref(), Some("old.tsv"));
+        assert_eq!(args("bench 7 --tolerance 5").unwrap().tolerance, Some(5.0));
+        assert!(args("bench 7 --iterations 0").is_err());
+        assert!(args("bench 7 --part 1").is_err());
+        assert!(args("bench").is_err());
+        assert!(args("7 bench").is_err());
+    }
+
+    #[test]
+    fn parse_new_args_test() {
+        let parsed = args("new 15").unwrap();
+        assert_eq!(parsed.command, Command::New);
+        assert_eq!(parsed.day, Some(15));
+        assert_eq!(args("fetch 3").unwrap().command, Command::Fetch);
+        assert!(args("fetch --all").is_err());
+        let parsed = args("submit 7 2").unwrap();
+        assert_eq!(parsed.command, Command::Submit);
+        assert_eq!((parsed.day, parsed.part), (Some(7), Some(2)));
+        assert!(args("submit 7").is_err());
+        assert!(args("submit 7 3").is_err());
+        assert!(args("submit 7 1 --example").is_err());
+        assert!(args("new").is_err());
+        assert!(args("new --all").is_err());
+        assert!(args("new 15 --example").is_err());
+        assert!(args("new bench 15").is_err());
+        assert!(args("7 --block 1").is_err());
+    }
+
+    #[test]
+    fn parse_extract_args_test() {
+        let parsed = args("extract 15 day15.html --block 2 --answer 1 --part 2").unwrap();
+        assert_eq!(parsed.command, Command::Extract);
+        assert_eq!(parsed.day, Some(15));
+        assert_eq!(parsed.page.as_deref(), Some("day15.html"));
+        assert_eq!((parsed.block, parsed.answer), (Some(2), Some(1)));
+        assert_eq!(parsed.part, Some(2));
+        assert_eq!(args("extract 15 day15.html").unwrap().block, None);
+        assert!(args("extract 15").is_err());
+        assert!(args("extract 15 day15.html --block 0").is_err());
+        assert!(args("extract 15 day15.html --example").is_err());
+    }
+
+    #[test]
+    fn example_input_test() {
+        assert_eq!(
+            example_input(3, 2, true).unwrap(),
+            "./input/day3_2_test.txt"
+        );
+        assert_eq!(
+            example_input(25, 1, false).unwrap(),
+            "./input/day25_test.txt"
+        );
+        let e = example_input(25, 1, true).unwrap_err();
+        assert!(e.contains("aoc new 25"), "{e}");
+    }
+
+    #[test]
+    fn selects_test() {
+        let parsed = args("1").unwrap();
+        assert_eq!(PUZZLES.iter().filter(|p| parsed.selects(p)).count(), 1);
+        assert_eq!(parsed.parts(), vec![1, 2]);
+        let parsed = args("1 --part 2").unwrap();
+        assert_eq!(parsed.parts(), vec![2]);
+        let parsed = args("--all").unwrap();
+        assert_eq!(
+            PUZZLES.iter().filter(|p| parsed.selects(p)).count(),
+            PUZZLES.len()
+        );
+    }
 }